@@ -0,0 +1,84 @@
+use anyhow::{anyhow, bail, Result};
+
+pub const SOL_DECIMALS: u8 = 9;
+
+// Parses decimal amount of whole tokens (e.g. "1.5") into raw amount, rejecting excess precision
+pub fn parse_ui_amount(amount: &str, decimals: u8) -> Result<u64> {
+    let (whole, fraction) = amount.trim().split_once('.').unwrap_or((amount.trim(), ""));
+    if whole.is_empty() && fraction.is_empty() {
+        bail!("invalid amount `{amount}`");
+    }
+    if fraction.len() > decimals as usize {
+        bail!("amount `{amount}` has more than {decimals} decimals");
+    }
+    if !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        bail!("invalid amount `{amount}`");
+    }
+    let digits = format!("{whole}{fraction:0<width$}", width = decimals as usize);
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    digits.parse().map_err(|_| anyhow!("amount `{amount}` is too large"))
+}
+
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    let divisor = 10u64.pow(decimals as u32);
+    let fraction = format!("{:0width$}", amount % divisor, width = decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        (amount / divisor).to_string()
+    } else {
+        format!("{}.{fraction}", amount / divisor)
+    }
+}
+
+// Price in SOL per whole token at a tick given as SOL per token (raw lamports per smallest token unit)
+pub fn sol_per_token_at_tick(tick_index: i32, token_decimals: u8) -> f64 {
+    1.0001f64.powi(tick_index) * 10f64.powi(token_decimals as i32 - SOL_DECIMALS as i32)
+}
+
+// Price in SOL per whole token at sqrt price in pool orientation (price of side A in side B)
+pub fn sol_per_token_at_sqrt_price(sqrt_price_x64: u128, token_is_mint_a: bool, token_decimals: u8) -> f64 {
+    let pool_price = (sqrt_price_x64 as f64 / 2f64.powi(64)).powi(2);
+    let raw_price = if token_is_mint_a { pool_price } else { 1.0 / pool_price };
+    raw_price * 10f64.powi(token_decimals as i32 - SOL_DECIMALS as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ui_amount() {
+        assert_eq!(parse_ui_amount("1", 9).unwrap(), 1_000_000_000);
+        assert_eq!(parse_ui_amount("1.5", 6).unwrap(), 1_500_000);
+        assert_eq!(parse_ui_amount(".25", 2).unwrap(), 25);
+        assert_eq!(parse_ui_amount("0.000", 3).unwrap(), 0);
+        assert_eq!(parse_ui_amount("18446744073.709551615", 9).unwrap(), u64::MAX);
+        assert!(parse_ui_amount("18446744073.709551616", 9).is_err());
+        assert!(parse_ui_amount("1.0001", 3).is_err());
+        assert!(parse_ui_amount("-1", 9).is_err());
+        assert!(parse_ui_amount(".", 9).is_err());
+        assert!(parse_ui_amount("1e9", 9).is_err());
+    }
+
+    #[test]
+    fn formats_ui_amount() {
+        assert_eq!(format_ui_amount(1_500_000, 6), "1.5");
+        assert_eq!(format_ui_amount(1_000_000_000, 9), "1");
+        assert_eq!(format_ui_amount(7, 0), "7");
+        assert_eq!(format_ui_amount(1, 9), "0.000000001");
+    }
+
+    #[test]
+    fn converts_tick_to_price() {
+        // Tick 0 is 1 lamport per smallest unit, i.e. 1 SOL per token for 9 decimals
+        assert!((sol_per_token_at_tick(0, 9) - 1.0).abs() < 1e-12);
+        assert!((sol_per_token_at_tick(0, 6) - 0.001).abs() < 1e-12);
+        assert!((sol_per_token_at_tick(-69082, 9) - 0.001).abs() < 1e-6);
+        // Pool price of 4 tokens per lamport when token is side B
+        assert!((sol_per_token_at_sqrt_price(2 << 64, false, 9) - 0.25).abs() < 1e-12);
+        assert!((sol_per_token_at_sqrt_price(2 << 64, true, 9) - 4.0).abs() < 1e-12);
+    }
+}
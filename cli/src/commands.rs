@@ -0,0 +1,476 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::{associated_token, token, token_2022};
+use anyhow::{anyhow, bail, Context, Result};
+use solana_sdk::address_lookup_table::instruction::{create_lookup_table, extend_lookup_table};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::{system_program, sysvar};
+use token_deployer_client::pda::{self, RaydiumAccounts};
+use token_deployer_client::token_deployer::state::{LaunchRecord, LauncherConfig, UpdateConfigArgs};
+use token_deployer_client::token_deployer::{self, metadata, raydium};
+use token_deployer_client::{
+    AddToRaydiumByMarketCap, CreateToken, CreateToken2022, HarvestFees, Initialize, InitializeConfig, LaunchAccounts,
+    UpdateConfig, MEMO_PROGRAM_ID, OBSERVATION_ACCOUNT_SPACE,
+};
+
+use crate::amount::{format_ui_amount, parse_ui_amount, sol_per_token_at_sqrt_price, sol_per_token_at_tick, SOL_DECIMALS};
+use crate::transaction::Sender;
+use crate::{ConfigArgs, CreateTokenArgs, LaunchArgs};
+
+const LAUNCH_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+const HARVEST_COMPUTE_UNIT_LIMIT: u32 = 600_000;
+// Offset of amm_config in Raydium PoolState: discriminator (8), bump (1)
+const POOL_AMM_CONFIG_OFFSET: usize = 9;
+
+pub fn init(sender: &Sender) -> Result<()> {
+    let payer = sender.payer.pubkey();
+    let mut instructions = vec![Initialize { payer }.instruction()];
+    let existing_config = fetch::<LauncherConfig>(sender, &pda::launcher_config())?;
+    if existing_config.is_none() {
+        println!("Launcher config {} does not exist, creating it with default values", pda::launcher_config());
+        instructions.push(InitializeConfig { admin: payer }.instruction());
+    }
+    sender.send("initialize", &instructions, &[])?;
+
+    let (fee_receiver, clmm_program_id) = match fetch::<LauncherConfig>(sender, &pda::launcher_config())? {
+        Some(config) => (config.fee_receiver, config.clmm_program_id),
+        None => (token_deployer::HARVESTER, token_deployer::CLMM_PROGRAM_ID),  // Defaults in dry run
+    };
+    let addresses = lookup_table_addresses(&fee_receiver, &clmm_program_id);
+    let recent_slot = sender.rpc.get_slot_with_commitment(CommitmentConfig::finalized())?;
+    let (create_instruction, lookup_table) = create_lookup_table(payer, payer, recent_slot);
+    let extend_instruction = extend_lookup_table(lookup_table, payer, Some(payer), addresses);
+    sender.send("create lookup table", &[create_instruction, extend_instruction], &[])?;
+    println!("Lookup table: {lookup_table}");
+    println!("Pass it to `launch` and `harvest` with --lookup-table {lookup_table} (or ROCKET_LOOKUP_TABLE)");
+    Ok(())
+}
+
+// Accounts shared by all launch and harvest transactions
+fn lookup_table_addresses(fee_receiver: &Pubkey, clmm_program_id: &Pubkey) -> Vec<Pubkey> {
+    vec![
+        system_program::ID,
+        token::ID,
+        token_2022::ID,
+        associated_token::ID,
+        mpl_token_metadata::ID,
+        MEMO_PROGRAM_ID,
+        sysvar::rent::ID,
+        native_mint::ID,
+        *clmm_program_id,
+        *fee_receiver,
+        pda::launcher_config(),
+        pda::position_nft_owner_pda(),
+        pda::event_authority(),
+    ]
+}
+
+pub fn config(sender: &Sender, args: ConfigArgs) -> Result<()> {
+    let updated = args.admin.is_some()
+        || args.fee.is_some()
+        || args.fee_receiver.is_some()
+        || args.clmm_program.is_some()
+        || args.min_liquidity_bps_of_supply.is_some()
+        || args.creator_fee_share_bps.is_some()
+        || args.keeper_tip.is_some()
+        || args.creator_withheld_fee_share_bps.is_some()
+        || args.min_supply.is_some()
+        || args.max_supply.is_some();
+    if updated {
+        let args = UpdateConfigArgs {
+            admin: args.admin,
+            fee_amount: args.fee.map(|fee| parse_ui_amount(&fee, SOL_DECIMALS)).transpose()?,
+            fee_receiver: args.fee_receiver,
+            clmm_program_id: args.clmm_program,
+            min_liquidity_bps_of_supply: args.min_liquidity_bps_of_supply,
+            creator_fee_share_bps: args.creator_fee_share_bps,
+            keeper_tip_lamports: args.keeper_tip.map(|tip| parse_ui_amount(&tip, SOL_DECIMALS)).transpose()?,
+            creator_withheld_fee_share_bps: args.creator_withheld_fee_share_bps,
+            min_supply: args.min_supply,
+            max_supply: args.max_supply,
+        };
+        sender.send("update config", &[UpdateConfig { admin: sender.payer.pubkey(), args }.instruction()], &[])?;
+    }
+    let config = fetch_launcher_config(sender)?;
+    println!("Launcher config: {}", pda::launcher_config());
+    println!("  admin: {}", config.admin);
+    println!("  fee: {} SOL", format_ui_amount(config.fee_amount, SOL_DECIMALS));
+    println!("  fee_receiver: {}", config.fee_receiver);
+    println!("  clmm_program_id: {}", config.clmm_program_id);
+    println!("  min_liquidity_bps_of_supply: {}", config.min_liquidity_bps_of_supply);
+    println!("  creator_fee_share_bps: {}", config.creator_fee_share_bps);
+    println!("  keeper_tip: {} SOL", format_ui_amount(config.keeper_tip_lamports, SOL_DECIMALS));
+    println!("  creator_withheld_fee_share_bps: {}", config.creator_withheld_fee_share_bps);
+    println!("  min_supply: {} tokens", config.min_supply);
+    println!("  max_supply: {} tokens", config.max_supply);
+    Ok(())
+}
+
+pub fn create_token(sender: &Sender, args: CreateTokenArgs) -> Result<()> {
+    let payer = sender.payer.pubkey();
+    let mint = match &args.mint_keypair {
+        Some(path) => read_keypair_file(path).map_err(|e| anyhow!("failed to read mint keypair {path}: {e}"))?,
+        None => Keypair::new(),
+    };
+    let amount = parse_ui_amount(&args.supply, args.decimals)?;
+    let symbol = metadata::normalize_symbol(&args.symbol).map_err(|e| anyhow!("invalid symbol: {e}"))?;
+    let token_program = if args.token_2022 { token_2022::ID } else { token::ID };
+    println!("Token mint: {}", mint.pubkey());
+    println!("  program: {}", if args.token_2022 { "Token-2022" } else { "SPL Token" });
+    println!("  supply: {} ({amount} raw, {} decimals)", args.supply, args.decimals);
+    println!("  payer token account: {}", get_associated_token_address_with_program_id(&payer, &mint.pubkey(), &token_program));
+    if args.token_2022 {
+        println!("  transfer fee: {} bps", args.transfer_fee_bps);
+    } else {
+        println!("  metadata account: {}", pda::metadata_account(&mint.pubkey()));
+    }
+    println!("  symbol registry ({symbol}): {}", pda::symbol_registry(&symbol));
+
+    let instruction = if args.token_2022 {
+        CreateToken2022 {
+            payer,
+            token_mint: mint.pubkey(),
+            amount,
+            decimals: args.decimals,
+            name: args.name,
+            symbol: args.symbol,
+            uri: args.uri,
+            transfer_fee_basis_points: args.transfer_fee_bps,
+        }.instruction()
+    } else {
+        CreateToken {
+            payer,
+            token_mint: mint.pubkey(),
+            amount,
+            decimals: args.decimals,
+            name: args.name,
+            symbol: args.symbol,
+            uri: args.uri,
+        }.instruction()
+    }.map_err(|e| anyhow!("{e}"))?;
+    sender.send("create token", &[instruction], &[&mint])?;
+    Ok(())
+}
+
+pub fn launch(sender: &Sender, args: LaunchArgs) -> Result<()> {
+    let payer = sender.payer.pubkey();
+    let config = fetch_launcher_config(sender)?;
+    let (token_mint_program, mint) = fetch_mint(sender, &args.mint)?;
+    let tick_spacing = fetch_tick_spacing(sender, &args.amm_config, &config.clmm_program_id)?;
+    let token_amount = match &args.token_amount {
+        Some(amount) => parse_ui_amount(amount, mint.decimals)?,
+        None => {
+            let token_account = get_associated_token_address_with_program_id(&payer, &args.mint, &token_mint_program);
+            sender.rpc.get_token_account_balance(&token_account)?.amount.parse()?
+        }
+    };
+    let observation = Keypair::new();
+    let position_nft_mint = Keypair::new();
+    let wsol_account = Keypair::new();
+    let builder = AddToRaydiumByMarketCap {
+        accounts: LaunchAccounts {
+            payer,
+            token_mint: args.mint,
+            token_mint_program,
+            symbol: fetch_token_symbol(sender, &args.mint, &token_mint_program)?,
+            amm_config: args.amm_config,
+            tick_spacing,
+            observation: observation.pubkey(),
+            position_nft_mint: position_nft_mint.pubkey(),
+            wsol_account: wsol_account.pubkey(),
+            fee_receiver: config.fee_receiver,
+            clmm_program: config.clmm_program_id,
+        },
+        supply: mint.supply,
+        initial_fdv_lamports: parse_ui_amount(&args.initial_fdv, SOL_DECIMALS)?,
+        upper_fdv_lamports: parse_ui_amount(&args.upper_fdv, SOL_DECIMALS)?,
+        token_amount,
+    };
+    let position = builder.position().map_err(|e| anyhow!("invalid position: {e}"))?;
+    let raydium = builder.raydium_accounts().map_err(|e| anyhow!("{e}"))?;
+
+    let token_is_mint_a = builder.accounts.token_is_mint_a();
+    let (tick_lower_index, tick_upper_index) = sol_per_token_tick_range(token_is_mint_a, position.tick_lower_index, position.tick_upper_index);
+    let supply = mint.supply as f64 / 10f64.powi(mint.decimals as i32);
+    let min_token_amount = (mint.supply as u128 * config.min_liquidity_bps_of_supply as u128).div_ceil(10_000);
+    println!("Price math:");
+    println!("  token is pool side {}, tick spacing {tick_spacing}", if token_is_mint_a { "A" } else { "B" });
+    println!("  pool tick range: [{}, {}]", position.tick_lower_index, position.tick_upper_index);
+    println!("  pool sqrt_price_x64: {}", position.sqrt_price_x64);
+    println!("  liquidity: {}", position.liquidity);
+    for (label, tick_index) in [("initial", tick_lower_index), ("upper", tick_upper_index)] {
+        let price = sol_per_token_at_tick(tick_index, mint.decimals);
+        println!("  {label} price: {price:.12} SOL per token (FDV {:.3} SOL)", price * supply);
+    }
+    println!(
+        "  token amount: {} of {} supply (at least {} required)",
+        format_ui_amount(token_amount, mint.decimals),
+        format_ui_amount(mint.supply, mint.decimals),
+        format_ui_amount(min_token_amount as u64, mint.decimals),
+    );
+    println!("  launch fee: {} SOL", format_ui_amount(config.fee_amount, SOL_DECIMALS));
+    println!("Accounts:");
+    println!("  launch record: {}", pda::launch_record(&args.mint));
+    println!("  observation: {}", observation.pubkey());
+    println!("  position NFT mint: {}", position_nft_mint.pubkey());
+    print_raydium_accounts(&raydium);
+
+    let rent_exempt_lamports = sender.rpc.get_minimum_balance_for_rent_exemption(OBSERVATION_ACCOUNT_SPACE as usize)?;
+    let instructions = [
+        ComputeBudgetInstruction::set_compute_unit_limit(LAUNCH_COMPUTE_UNIT_LIMIT),
+        builder.accounts.create_observation_account(rent_exempt_lamports),
+        builder.instruction().map_err(|e| anyhow!("{e}"))?,
+    ];
+    sender.send("launch", &instructions, &[&observation, &position_nft_mint, &wsol_account])?;
+    Ok(())
+}
+
+pub fn harvest(sender: &Sender, mint: Pubkey) -> Result<()> {
+    let config = fetch_launcher_config(sender)?;
+    let launch_record = fetch_launch_record(sender, &mint)?;
+    let (token_mint_program, _) = fetch_mint(sender, &mint)?;
+    let (amm_config, tick_spacing) = fetch_pool_amm_config(sender, &launch_record.pool_id, &config.clmm_program_id)?;
+    let wsol_recipient_account = Keypair::new();
+    let builder = HarvestFees::from_launch_record(
+        &launch_record,
+        sender.payer.pubkey(),
+        wsol_recipient_account.pubkey(),
+        token_mint_program,
+        amm_config,
+        tick_spacing,
+        config.fee_receiver,
+        config.clmm_program_id,
+    );
+    let raydium = builder.raydium_accounts();
+    if raydium.pool_id != launch_record.pool_id {
+        bail!("derived pool {} does not match launch record pool {}", raydium.pool_id, launch_record.pool_id);
+    }
+    println!("Harvesting position {} of {mint}", launch_record.position_nft_mint);
+    print_raydium_accounts(&raydium);
+    let instructions = [
+        ComputeBudgetInstruction::set_compute_unit_limit(HARVEST_COMPUTE_UNIT_LIMIT),
+        builder.instruction(),
+    ];
+    sender.send("harvest", &instructions, &[&wsol_recipient_account])?;
+    Ok(())
+}
+
+pub fn show_launch(sender: &Sender, mint: Pubkey) -> Result<()> {
+    let config = fetch_launcher_config(sender)?;
+    let launch_record = fetch_launch_record(sender, &mint)?;
+    let (_, token_mint) = fetch_mint(sender, &mint)?;
+    let (amm_config, tick_spacing) = fetch_pool_amm_config(sender, &launch_record.pool_id, &config.clmm_program_id)?;
+    let (tick_lower_index, tick_upper_index) = sol_per_token_tick_range(
+        launch_record.token_is_mint_a,
+        launch_record.tick_lower_index,
+        launch_record.tick_upper_index,
+    );
+    let initial_price = sol_per_token_at_sqrt_price(
+        launch_record.initial_sqrt_price_x64,
+        launch_record.token_is_mint_a,
+        token_mint.decimals,
+    );
+    println!("Launch record: {}", pda::launch_record(&mint));
+    println!("  creator: {}", launch_record.creator);
+    println!("  token mint: {}", launch_record.token_mint);
+    println!("  pool: {} (AMM config {amm_config}, tick spacing {tick_spacing})", launch_record.pool_id);
+    println!("  position NFT mint: {}", launch_record.position_nft_mint);
+    println!("  token is pool side {}", if launch_record.token_is_mint_a { "A" } else { "B" });
+    println!("  pool tick range: [{}, {}]", launch_record.tick_lower_index, launch_record.tick_upper_index);
+    println!("  initial price: {initial_price:.12} SOL per token");
+    println!(
+        "  price range: {:.12} - {:.12} SOL per token",
+        sol_per_token_at_tick(tick_lower_index, token_mint.decimals),
+        sol_per_token_at_tick(tick_upper_index, token_mint.decimals),
+    );
+    println!("  liquidity: {}", launch_record.liquidity);
+    println!("  launched at slot {} (unix time {})", launch_record.launch_slot, launch_record.launch_timestamp);
+    println!("  token harvested: {}", format_ui_amount(launch_record.token_harvested, token_mint.decimals));
+    println!("  WSOL harvested: {}", format_ui_amount(launch_record.wsol_harvested, SOL_DECIMALS));
+    let raydium = RaydiumAccounts::derive(
+        &config.clmm_program_id,
+        &amm_config,
+        tick_spacing,
+        &mint,
+        &native_mint::ID,
+        &launch_record.position_nft_mint,
+        launch_record.tick_lower_index,
+        launch_record.tick_upper_index,
+    );
+    print_raydium_accounts(&raydium);
+    Ok(())
+}
+
+fn print_raydium_accounts(raydium: &RaydiumAccounts) {
+    println!("Raydium accounts:");
+    println!("  pool: {}", raydium.pool_id);
+    println!("  token vault: {}", raydium.token_vault);
+    println!("  WSOL vault: {}", raydium.wsol_vault);
+    println!("  tick array bitmap extension: {}", raydium.ex_tick_array_bitmap);
+    println!("  protocol position: {}", raydium.protocol_position);
+    println!("  tick array lower: {}", raydium.tick_array_lower);
+    println!("  tick array upper: {}", raydium.tick_array_upper);
+    println!("  personal position: {}", raydium.personal_position);
+    println!("  position NFT account: {}", raydium.position_nft_account);
+    println!("  position NFT metadata: {}", raydium.position_nft_metadata);
+}
+
+// Converts tick range in pool orientation back to SOL per token
+fn sol_per_token_tick_range(token_is_mint_a: bool, tick_lower_index: i32, tick_upper_index: i32) -> (i32, i32) {
+    if token_is_mint_a {
+        (tick_lower_index, tick_upper_index)
+    } else {
+        (-tick_upper_index, -tick_lower_index)
+    }
+}
+
+fn fetch<T: AccountDeserialize + Discriminator>(sender: &Sender, address: &Pubkey) -> Result<Option<T>> {
+    let account = sender.rpc.get_account_with_commitment(address, sender.rpc.commitment())?.value;
+    account
+        .map(|account| T::try_deserialize(&mut &account.data[..]).map_err(|e| anyhow!("invalid account {address}: {e}")))
+        .transpose()
+}
+
+fn fetch_launcher_config(sender: &Sender) -> Result<LauncherConfig> {
+    fetch(sender, &pda::launcher_config())?.context("launcher config does not exist, run `rocket init` first")
+}
+
+fn fetch_launch_record(sender: &Sender, mint: &Pubkey) -> Result<LaunchRecord> {
+    fetch(sender, &pda::launch_record(mint))?.with_context(|| format!("launch record of {mint} does not exist"))
+}
+
+// Returns the mint's token program (legacy or Token-2022) and mint state
+fn fetch_mint(sender: &Sender, mint: &Pubkey) -> Result<(Pubkey, Mint)> {
+    let account = sender.rpc.get_account(mint).with_context(|| format!("mint {mint} not found"))?;
+    if account.owner != token::ID && account.owner != token_2022::ID {
+        bail!("{mint} is not a token mint");
+    }
+    let state = Mint::try_deserialize(&mut &account.data[..]).map_err(|e| anyhow!("invalid mint {mint}: {e}"))?;
+    Ok((account.owner, state))
+}
+
+// Returns symbol of token metadata, stored on the mint for Token-2022 tokens or in Metaplex metadata account
+fn fetch_token_symbol(sender: &Sender, mint: &Pubkey, token_mint_program: &Pubkey) -> Result<String> {
+    if *token_mint_program == token_2022::ID {
+        let data = sender.rpc.get_account_data(mint).with_context(|| format!("mint {mint} not found"))?;
+        let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)
+            .map_err(|e| anyhow!("invalid mint {mint}: {e}"))?;
+        let metadata = state
+            .get_variable_len_extension::<TokenMetadata>()
+            .map_err(|e| anyhow!("mint {mint} has no token metadata: {e}"))?;
+        return Ok(metadata.symbol);
+    }
+    let metadata_account = pda::metadata_account(mint);
+    let data = sender.rpc.get_account_data(&metadata_account)
+        .with_context(|| format!("metadata {metadata_account} of {mint} not found"))?;
+    let metadata = mpl_token_metadata::accounts::Metadata::safe_deserialize(&data)
+        .map_err(|e| anyhow!("invalid metadata {metadata_account}: {e}"))?;
+    // Metaplex pads symbol with null characters
+    Ok(metadata.symbol.trim_end_matches('\0').to_string())
+}
+
+fn fetch_tick_spacing(sender: &Sender, amm_config: &Pubkey, clmm_program_id: &Pubkey) -> Result<u16> {
+    let account = sender.rpc.get_account(amm_config).with_context(|| format!("AMM config {amm_config} not found"))?;
+    if account.owner != *clmm_program_id {
+        bail!("AMM config {amm_config} is not owned by CLMM program {clmm_program_id}");
+    }
+    raydium::parse_tick_spacing(&account.data).map_err(|e| anyhow!("invalid AMM config {amm_config}: {e}"))
+}
+
+// Returns AMM config of a Raydium pool and its tick spacing
+fn fetch_pool_amm_config(sender: &Sender, pool: &Pubkey, clmm_program_id: &Pubkey) -> Result<(Pubkey, u16)> {
+    let data = sender.rpc.get_account_data(pool).with_context(|| format!("pool {pool} not found"))?;
+    let amm_config = data
+        .get(POOL_AMM_CONFIG_OFFSET..POOL_AMM_CONFIG_OFFSET + 32)
+        .and_then(|bytes| Pubkey::try_from(bytes).ok())
+        .with_context(|| format!("invalid pool {pool}"))?;
+    Ok((amm_config, fetch_tick_spacing(sender, &amm_config, clmm_program_id)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::address_lookup_table::AddressLookupTableAccount;
+    use solana_sdk::hash::Hash;
+    use solana_sdk::instruction::Instruction;
+    use solana_sdk::message::{v0, VersionedMessage};
+    use solana_sdk::packet::PACKET_DATA_SIZE;
+    use solana_sdk::transaction::VersionedTransaction;
+
+    fn transaction_size(payer: &Keypair, instructions: &[Instruction], signers: &[&Keypair], lookup_tables: &[AddressLookupTableAccount]) -> usize {
+        let message = v0::Message::try_compile(&payer.pubkey(), instructions, lookup_tables, Hash::default()).unwrap();
+        let mut all_signers: Vec<&dyn Signer> = vec![payer];
+        all_signers.extend(signers.iter().map(|signer| *signer as &dyn Signer));
+        let transaction = VersionedTransaction::try_new(VersionedMessage::V0(message), &all_signers).unwrap();
+        bincode::serialized_size(&transaction).unwrap() as usize
+    }
+
+    // Launch and harvest transactions only fit into a packet with the lookup table created by `init`
+    #[test]
+    fn launch_and_harvest_fit_with_lookup_table() {
+        let payer = Keypair::new();
+        let fee_receiver = Pubkey::new_unique();
+        let lookup_table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: lookup_table_addresses(&fee_receiver, &token_deployer::CLMM_PROGRAM_ID),
+        };
+        let (observation, position_nft_mint, wsol_account) = (Keypair::new(), Keypair::new(), Keypair::new());
+        let launch = AddToRaydiumByMarketCap {
+            accounts: LaunchAccounts {
+                payer: payer.pubkey(),
+                token_mint: Pubkey::new_unique(),
+                token_mint_program: token_2022::ID,
+                symbol: "RKT".to_string(),
+                amm_config: Pubkey::new_unique(),
+                tick_spacing: 60,
+                observation: observation.pubkey(),
+                position_nft_mint: position_nft_mint.pubkey(),
+                wsol_account: wsol_account.pubkey(),
+                fee_receiver,
+                clmm_program: token_deployer::CLMM_PROGRAM_ID,
+            },
+            supply: 1_000_000_000_000_000,
+            initial_fdv_lamports: 10_000_000_000,
+            upper_fdv_lamports: 1_000_000_000_000,
+            token_amount: 1_000_000_000_000_000,
+        };
+        let launch_instructions = [
+            ComputeBudgetInstruction::set_compute_unit_limit(LAUNCH_COMPUTE_UNIT_LIMIT),
+            launch.accounts.create_observation_account(0),
+            launch.instruction().unwrap(),
+        ];
+        let launch_signers = [&observation, &position_nft_mint, &wsol_account];
+        assert!(transaction_size(&payer, &launch_instructions, &launch_signers, &[]) > PACKET_DATA_SIZE);
+        assert!(transaction_size(&payer, &launch_instructions, &launch_signers, std::slice::from_ref(&lookup_table)) <= PACKET_DATA_SIZE);
+
+        let wsol_recipient_account = Keypair::new();
+        let harvest = HarvestFees {
+            payer: payer.pubkey(),
+            wsol_recipient_account: wsol_recipient_account.pubkey(),
+            token_mint: launch.accounts.token_mint,
+            token_mint_program: token_2022::ID,
+            amm_config: launch.accounts.amm_config,
+            tick_spacing: 60,
+            position_nft_mint: position_nft_mint.pubkey(),
+            pool_tick_lower_index: -3600,
+            pool_tick_upper_index: 3540,
+            creator: Some(Pubkey::new_unique()),
+            fee_receiver,
+            clmm_program: token_deployer::CLMM_PROGRAM_ID,
+        };
+        let harvest_instructions = [
+            ComputeBudgetInstruction::set_compute_unit_limit(HARVEST_COMPUTE_UNIT_LIMIT),
+            harvest.instruction(),
+        ];
+        assert!(transaction_size(&payer, &harvest_instructions, &[&wsol_recipient_account], &[lookup_table]) <= PACKET_DATA_SIZE);
+    }
+}
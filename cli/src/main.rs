@@ -0,0 +1,146 @@
+mod amount;
+mod commands;
+mod transaction;
+
+use anyhow::{anyhow, Result};
+use clap::{Args, Parser, Subcommand};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::read_keypair_file;
+
+use transaction::Sender;
+
+#[derive(Parser)]
+#[command(name = "rocket", about = "Deploys tokens and launches them on Raydium CLMM with token_deployer program")]
+struct Cli {
+    /// RPC URL of the cluster
+    #[arg(short, long, global = true, env = "ROCKET_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+    /// Keypair of the payer (and admin for config commands)
+    #[arg(short, long, global = true, env = "ROCKET_KEYPAIR", default_value = "~/.config/solana/id.json")]
+    keypair: String,
+    /// Address lookup table created by `init`, required for `launch` and `harvest` transactions
+    #[arg(long, global = true, env = "ROCKET_LOOKUP_TABLE")]
+    lookup_table: Option<Pubkey>,
+    /// Only print computed accounts and amounts, without simulating or sending transactions
+    #[arg(long, global = true)]
+    dry_run: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Creates position NFT owner, launcher config (if missing) and address lookup table for launch transactions
+    Init,
+    /// Shows launcher config, or updates it when any option is given
+    Config(ConfigArgs),
+    /// Creates token with metadata and mints total supply to the payer
+    CreateToken(CreateTokenArgs),
+    /// Creates TOKEN/WSOL Raydium CLMM pool and opens single-sided position described by market caps
+    Launch(LaunchArgs),
+    /// Harvests fees of a launched position
+    Harvest(MintArgs),
+    /// Shows launch record and Raydium accounts of a launched token
+    ShowLaunch(MintArgs),
+}
+
+#[derive(Args)]
+struct ConfigArgs {
+    #[arg(long)]
+    admin: Option<Pubkey>,
+    /// Launch fee, in SOL
+    #[arg(long)]
+    fee: Option<String>,
+    #[arg(long)]
+    fee_receiver: Option<Pubkey>,
+    #[arg(long)]
+    clmm_program: Option<Pubkey>,
+    #[arg(long)]
+    min_liquidity_bps_of_supply: Option<u64>,
+    #[arg(long)]
+    creator_fee_share_bps: Option<u16>,
+    /// Keeper tip, in SOL
+    #[arg(long)]
+    keeper_tip: Option<String>,
+    #[arg(long)]
+    creator_withheld_fee_share_bps: Option<u16>,
+    /// Minimum total supply, in whole tokens
+    #[arg(long)]
+    min_supply: Option<u64>,
+    /// Maximum total supply, in whole tokens
+    #[arg(long)]
+    max_supply: Option<u64>,
+}
+
+#[derive(Args)]
+struct CreateTokenArgs {
+    #[arg(long)]
+    name: String,
+    #[arg(long)]
+    symbol: String,
+    #[arg(long)]
+    uri: String,
+    /// Total supply, in whole tokens
+    #[arg(long)]
+    supply: String,
+    #[arg(long, default_value_t = 9)]
+    decimals: u8,
+    /// Creates Token-2022 token instead of legacy SPL token
+    #[arg(long)]
+    token_2022: bool,
+    /// Token-2022 transfer fee, in basis points
+    #[arg(long, default_value_t = 0, requires = "token_2022")]
+    transfer_fee_bps: u16,
+    /// Keypair of the new mint, random by default
+    #[arg(long)]
+    mint_keypair: Option<String>,
+}
+
+#[derive(Args)]
+struct LaunchArgs {
+    #[arg(long)]
+    mint: Pubkey,
+    /// Raydium AMM config of the pool
+    #[arg(long)]
+    amm_config: Pubkey,
+    /// Fully diluted valuation at the pool's initial price, in SOL
+    #[arg(long)]
+    initial_fdv: String,
+    /// Fully diluted valuation at the upper bound of the position, in SOL
+    #[arg(long)]
+    upper_fdv: String,
+    /// Amount of tokens to provide to the position, in whole tokens. Payer's entire balance by default.
+    #[arg(long)]
+    token_amount: Option<String>,
+}
+
+#[derive(Args)]
+struct MintArgs {
+    #[arg(long)]
+    mint: Pubkey,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let payer = read_keypair_file(expand_home(&cli.keypair))
+        .map_err(|e| anyhow!("failed to read keypair {}: {e}", cli.keypair))?;
+    let mut sender = Sender::new(cli.url, payer, cli.dry_run);
+    if let Some(lookup_table) = cli.lookup_table {
+        sender.use_lookup_table(lookup_table)?;
+    }
+    match cli.command {
+        Command::Init => commands::init(&sender),
+        Command::Config(args) => commands::config(&sender, args),
+        Command::CreateToken(args) => commands::create_token(&sender, args),
+        Command::Launch(args) => commands::launch(&sender, args),
+        Command::Harvest(args) => commands::harvest(&sender, args.mint),
+        Command::ShowLaunch(args) => commands::show_launch(&sender, args.mint),
+    }
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
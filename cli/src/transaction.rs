@@ -0,0 +1,97 @@
+use anyhow::{anyhow, bail, Context, Result};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::address_lookup_table::state::AddressLookupTable;
+use solana_sdk::address_lookup_table::AddressLookupTableAccount;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::{v0, VersionedMessage};
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::VersionedTransaction;
+
+// Builds, previews and sends transactions signed by the payer
+pub struct Sender {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+    pub dry_run: bool,
+    lookup_tables: Vec<AddressLookupTableAccount>,
+}
+
+impl Sender {
+    pub fn new(url: String, payer: Keypair, dry_run: bool) -> Self {
+        Self {
+            rpc: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()),
+            payer,
+            dry_run,
+            lookup_tables: Vec::new(),
+        }
+    }
+
+    // Loads address lookup table used to compile transactions that do not fit with static account keys
+    pub fn use_lookup_table(&mut self, address: Pubkey) -> Result<()> {
+        let data = self.rpc.get_account_data(&address).with_context(|| format!("lookup table {address} not found"))?;
+        let table = AddressLookupTable::deserialize(&data).map_err(|e| anyhow!("invalid lookup table {address}: {e}"))?;
+        self.lookup_tables.push(AddressLookupTableAccount { key: address, addresses: table.addresses.to_vec() });
+        Ok(())
+    }
+
+    // Prints simulation of the transaction and sends it if simulation succeeded.
+    // Does nothing with --dry-run, which only prints computed accounts and amounts.
+    pub fn send(&self, description: &str, instructions: &[Instruction], signers: &[&Keypair]) -> Result<Option<Signature>> {
+        if self.dry_run {
+            println!("[dry run] {description}: not sent");
+            return Ok(None);
+        }
+        let transaction = self.build(instructions, signers)?;
+        self.simulate(description, &transaction)?;
+        let signature = self
+            .rpc
+            .send_and_confirm_transaction(&transaction)
+            .with_context(|| format!("{description} failed"))?;
+        println!("{description}: {signature}");
+        Ok(Some(signature))
+    }
+
+    fn build(&self, instructions: &[Instruction], signers: &[&Keypair]) -> Result<VersionedTransaction> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let message = v0::Message::try_compile(&self.payer.pubkey(), instructions, &self.lookup_tables, blockhash)?;
+        let mut all_signers: Vec<&dyn Signer> = vec![&self.payer];
+        all_signers.extend(signers.iter().map(|signer| *signer as &dyn Signer));
+        let transaction = VersionedTransaction::try_new(VersionedMessage::V0(message), &all_signers)?;
+        let size = bincode::serialized_size(&transaction)? as usize;
+        if size > PACKET_DATA_SIZE {
+            bail!(
+                "transaction is {size} bytes, over {PACKET_DATA_SIZE} byte limit; \
+                pass lookup table created by `rocket init` with --lookup-table"
+            );
+        }
+        Ok(transaction)
+    }
+
+    fn simulate(&self, description: &str, transaction: &VersionedTransaction) -> Result<()> {
+        let simulation = self
+            .rpc
+            .simulate_transaction_with_config(
+                transaction,
+                RpcSimulateTransactionConfig {
+                    sig_verify: true,
+                    commitment: Some(CommitmentConfig::processed()),
+                    ..RpcSimulateTransactionConfig::default()
+                },
+            )?
+            .value;
+        println!("Simulation of {description}:");
+        for log in simulation.logs.unwrap_or_default() {
+            println!("  {log}");
+        }
+        if let Some(units_consumed) = simulation.units_consumed {
+            println!("  Compute units consumed: {units_consumed}");
+        }
+        if let Some(err) = simulation.err {
+            bail!("simulation of {description} failed: {err}");
+        }
+        Ok(())
+    }
+}
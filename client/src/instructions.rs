@@ -0,0 +1,992 @@
+use anchor_lang::prelude::*;
+use solana_program::{instruction::Instruction, system_instruction, sysvar::rent};
+use anchor_lang::{system_program, InstructionData};
+use anchor_spl::associated_token::{self, get_associated_token_address, get_associated_token_address_with_program_id};
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::token::{self, spl_token::native_mint};
+use anchor_spl::token_2022;
+use token_deployer::math::{self, PoolPosition};
+use token_deployer::metadata;
+use token_deployer::state::{LaunchRecord, UpdateConfigArgs};
+
+use crate::pda::{self, RaydiumAccounts};
+
+pub use token_deployer::MEMO_PROGRAM_ID;
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: token_deployer::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// Creates position_nft_owner_pda
+pub struct Initialize {
+    pub payer: Pubkey,
+}
+
+impl Initialize {
+    pub fn instruction(&self) -> Instruction {
+        instruction(
+            token_deployer::accounts::Initialize {
+                position_nft_owner_pda: pda::position_nft_owner_pda(),
+                payer: self.payer,
+                system_program: system_program::ID,
+                rent_program: rent::ID,
+            },
+            token_deployer::instruction::Initialize {},
+        )
+    }
+}
+
+// Creates legacy SPL token with Metaplex metadata. `token_mint` must sign the transaction.
+pub struct CreateToken {
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub decimals: u8,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+impl CreateToken {
+    // Fails with the program's error if the symbol is invalid, as symbol registry is derived from the normalized symbol
+    pub fn instruction(&self) -> Result<Instruction> {
+        let symbol = metadata::normalize_symbol(&self.symbol)?;
+        Ok(instruction(
+            token_deployer::accounts::CreateTokenAccounts {
+                config: pda::launcher_config(),
+                payer: self.payer,
+                token_mint: self.token_mint,
+                token_account: get_associated_token_address(&self.payer, &self.token_mint),
+                token_metadata_account: pda::metadata_account(&self.token_mint),
+                symbol_registry: pda::symbol_registry(&symbol),
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                metadata_program: mpl_token_metadata::ID,
+                rent_program: rent::ID,
+                event_authority: pda::event_authority(),
+                program: token_deployer::ID,
+            },
+            token_deployer::instruction::CreateToken {
+                amount: self.amount,
+                decimals: self.decimals,
+                name: self.name.clone(),
+                symbol: self.symbol.clone(),
+                uri: self.uri.clone(),
+            },
+        ))
+    }
+}
+
+// Creates Token-2022 token with metadata extensions and optional transfer fee.
+// `token_mint` must sign the transaction.
+pub struct CreateToken2022 {
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub decimals: u8,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub transfer_fee_basis_points: u16,
+}
+
+impl CreateToken2022 {
+    pub fn instruction(&self) -> Result<Instruction> {
+        let symbol = metadata::normalize_symbol(&self.symbol)?;
+        Ok(instruction(
+            token_deployer::accounts::CreateToken2022Accounts {
+                config: pda::launcher_config(),
+                payer: self.payer,
+                token_mint: self.token_mint,
+                token_account: get_associated_token_address_with_program_id(&self.payer, &self.token_mint, &token_2022::ID),
+                withheld_fee_authority: pda::withheld_fee_authority(),
+                symbol_registry: pda::symbol_registry(&symbol),
+                system_program: system_program::ID,
+                token_program: token_2022::ID,
+                associated_token_program: associated_token::ID,
+                event_authority: pda::event_authority(),
+                program: token_deployer::ID,
+            },
+            token_deployer::instruction::CreateToken2022 {
+                amount: self.amount,
+                decimals: self.decimals,
+                name: self.name.clone(),
+                symbol: self.symbol.clone(),
+                uri: self.uri.clone(),
+                transfer_fee_basis_points: self.transfer_fee_basis_points,
+            },
+        ))
+    }
+}
+
+// Creates launcher config with default values. `admin` must be the program's upgrade authority.
+pub struct InitializeConfig {
+    pub admin: Pubkey,
+}
+
+impl InitializeConfig {
+    pub fn instruction(&self) -> Instruction {
+        instruction(
+            token_deployer::accounts::InitializeConfig {
+                config: pda::launcher_config(),
+                admin: self.admin,
+                program: token_deployer::ID,
+                program_data: pda::program_data(),
+                system_program: system_program::ID,
+            },
+            token_deployer::instruction::InitializeConfig {},
+        )
+    }
+}
+
+pub struct UpdateConfig {
+    pub admin: Pubkey,
+    pub args: UpdateConfigArgs,
+}
+
+impl UpdateConfig {
+    pub fn instruction(&self) -> Instruction {
+        instruction(
+            token_deployer::accounts::UpdateConfig {
+                config: pda::launcher_config(),
+                admin: self.admin,
+            },
+            token_deployer::instruction::UpdateConfig { args: self.args.clone() },
+        )
+    }
+}
+
+// Reserves symbol so that no token can be created with it. Fails with the program's error if the symbol is invalid.
+pub struct ReserveSymbol {
+    pub admin: Pubkey,
+    pub symbol: String,
+}
+
+impl ReserveSymbol {
+    pub fn instruction(&self) -> Result<Instruction> {
+        Ok(instruction(
+            token_deployer::accounts::ReserveSymbol {
+                config: pda::launcher_config(),
+                symbol_registry: pda::symbol_registry(&metadata::normalize_symbol(&self.symbol)?),
+                admin: self.admin,
+                system_program: system_program::ID,
+            },
+            token_deployer::instruction::ReserveSymbol { symbol: self.symbol.clone() },
+        ))
+    }
+}
+
+// Releases reserved symbol, closing its registry to `admin`
+pub struct ReleaseSymbol {
+    pub admin: Pubkey,
+    pub symbol: String,
+}
+
+impl ReleaseSymbol {
+    pub fn instruction(&self) -> Result<Instruction> {
+        Ok(instruction(
+            token_deployer::accounts::ReleaseSymbol {
+                config: pda::launcher_config(),
+                symbol_registry: pda::symbol_registry(&metadata::normalize_symbol(&self.symbol)?),
+                admin: self.admin,
+            },
+            token_deployer::instruction::ReleaseSymbol { _symbol: self.symbol.clone() },
+        ))
+    }
+}
+
+// Size of Raydium ObservationState account, checked by add_to_raydium
+pub const OBSERVATION_ACCOUNT_SPACE: u64 = token_deployer::raydium::OBSERVATION_STATE_LEN as u64;
+
+// Accounts of add_to_raydium and add_to_raydium_by_market_cap.
+// `position_nft_mint` and `wsol_account` must sign the transaction, and `observation` account
+// must be created for the CLMM program earlier in the same transaction (see create_observation_account),
+// as it is too large to be allocated by the program in CPI.
+pub struct LaunchAccounts {
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_mint_program: Pubkey,  // Legacy token program or Token-2022
+    pub symbol: String,              // Symbol of token metadata, must be registered to the token
+    pub amm_config: Pubkey,
+    pub tick_spacing: u16,           // Tick spacing of `amm_config`
+    pub observation: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub wsol_account: Pubkey,
+    pub fee_receiver: Pubkey,        // From launcher config
+    pub clmm_program: Pubkey,        // From launcher config
+}
+
+impl LaunchAccounts {
+    pub fn token_is_mint_a(&self) -> bool {
+        pda::token_is_mint_a(&self.token_mint, &native_mint::ID)
+    }
+
+    // Tick range is in pool orientation
+    pub fn raydium_accounts(&self, pool_tick_lower_index: i32, pool_tick_upper_index: i32) -> RaydiumAccounts {
+        RaydiumAccounts::derive(
+            &self.clmm_program,
+            &self.amm_config,
+            self.tick_spacing,
+            &self.token_mint,
+            &native_mint::ID,
+            &self.position_nft_mint,
+            pool_tick_lower_index,
+            pool_tick_upper_index,
+        )
+    }
+
+    // Token-2022 tokens (as created by create_token_2022) store metadata on the mint itself
+    pub fn token_metadata_account(&self) -> Pubkey {
+        if self.token_mint_program == token_2022::ID {
+            self.token_mint
+        } else {
+            pda::metadata_account(&self.token_mint)
+        }
+    }
+
+    fn accounts(&self, raydium: RaydiumAccounts) -> Result<token_deployer::accounts::AddToRaydiumAccounts> {
+        Ok(token_deployer::accounts::AddToRaydiumAccounts {
+            config: pda::launcher_config(),
+            payer: self.payer,
+            token_mint: self.token_mint,
+            token_account: get_associated_token_address_with_program_id(
+                &self.payer,
+                &self.token_mint,
+                &self.token_mint_program,
+            ),
+            token_metadata_account: self.token_metadata_account(),
+            symbol_registry: pda::symbol_registry(&metadata::normalize_symbol(&self.symbol)?),
+            launch_record: pda::launch_record(&self.token_mint),
+            raydium: self.raydium_launch_accounts(raydium),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            token_2022_program: token_2022::ID,
+            metadata_program: mpl_token_metadata::ID,
+            rent_program: rent::ID,
+            event_authority: pda::event_authority(),
+            program: token_deployer::ID,
+        })
+    }
+
+    // Raydium pool and position accounts shared by launch instructions
+    fn raydium_launch_accounts(&self, raydium: RaydiumAccounts) -> token_deployer::accounts::RaydiumLaunchAccounts {
+        token_deployer::accounts::RaydiumLaunchAccounts {
+            amm_config_id: self.amm_config,
+            pool_id: raydium.pool_id,
+            wsol_mint: native_mint::ID,
+            token_vault: raydium.token_vault,
+            wsol_vault: raydium.wsol_vault,
+            observation_id: self.observation,
+            ex_tick_array_bitmap: raydium.ex_tick_array_bitmap,
+            position_nft_mint: self.position_nft_mint,
+            position_nft_owner_pda: pda::position_nft_owner_pda(),
+            position_nft_account: raydium.position_nft_account,
+            metadata_account: raydium.position_nft_metadata,
+            protocol_position: raydium.protocol_position,
+            tick_array_lower: raydium.tick_array_lower,
+            tick_array_upper: raydium.tick_array_upper,
+            personal_position: raydium.personal_position,
+            wsol_account: self.wsol_account,
+            fee_receiver: self.fee_receiver,
+            clmm_program: self.clmm_program,
+        }
+    }
+
+    // Creates zeroed observation account owned by the CLMM program, which Raydium initializes in create_pool.
+    // `observation` must sign the transaction.
+    pub fn create_observation_account(&self, rent_exempt_lamports: u64) -> Instruction {
+        system_instruction::create_account(
+            &self.payer,
+            &self.observation,
+            rent_exempt_lamports,
+            OBSERVATION_ACCOUNT_SPACE,
+            &self.clmm_program,
+        )
+    }
+}
+
+// Creates TOKEN/WSOL pool and opens position. Price and tick range are SOL per token, as in add_to_raydium.
+pub struct AddToRaydium {
+    pub accounts: LaunchAccounts,
+    pub sqrt_price_x64: u128,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub token_amount_max: u64,
+    pub wsol_amount_max: u64,
+}
+
+impl AddToRaydium {
+    pub fn raydium_accounts(&self) -> Result<RaydiumAccounts> {
+        let (tick_lower_index, tick_upper_index) =
+            pda::pool_tick_range(self.accounts.token_is_mint_a(), self.tick_lower_index, self.tick_upper_index)?;
+        Ok(self.accounts.raydium_accounts(tick_lower_index, tick_upper_index))
+    }
+
+    pub fn instruction(&self) -> Result<Instruction> {
+        Ok(instruction(
+            self.accounts.accounts(self.raydium_accounts()?)?,
+            token_deployer::instruction::AddToRaydium {
+                sqrt_price_x64: self.sqrt_price_x64,
+                tick_lower_index: self.tick_lower_index,
+                tick_upper_index: self.tick_upper_index,
+                liquidity: self.liquidity,
+                token_amount_max: self.token_amount_max,
+                wsol_amount_max: self.wsol_amount_max,
+            },
+        ))
+    }
+}
+
+// Creates TOKEN/WSOL pool and opens single-sided position described by market caps (in lamports).
+// `supply` is the token mint's supply, which the program uses to compute the position.
+pub struct AddToRaydiumByMarketCap {
+    pub accounts: LaunchAccounts,
+    pub supply: u64,
+    pub initial_fdv_lamports: u64,
+    pub upper_fdv_lamports: u64,
+    pub token_amount: u64,
+}
+
+impl AddToRaydiumByMarketCap {
+    // Position the program computes on-chain, in pool orientation
+    pub fn position(&self) -> Result<PoolPosition> {
+        math::position_from_market_cap(
+            self.accounts.token_is_mint_a(),
+            self.initial_fdv_lamports,
+            self.upper_fdv_lamports,
+            self.supply,
+            self.token_amount,
+            self.accounts.tick_spacing,
+        )
+    }
+
+    pub fn raydium_accounts(&self) -> Result<RaydiumAccounts> {
+        let position = self.position()?;
+        Ok(self.accounts.raydium_accounts(position.tick_lower_index, position.tick_upper_index))
+    }
+
+    pub fn instruction(&self) -> Result<Instruction> {
+        Ok(instruction(
+            self.accounts.accounts(self.raydium_accounts()?)?,
+            token_deployer::instruction::AddToRaydiumByMarketCap {
+                initial_fdv_lamports: self.initial_fdv_lamports,
+                upper_fdv_lamports: self.upper_fdv_lamports,
+                token_amount: self.token_amount,
+            },
+        ))
+    }
+}
+
+// Creates legacy SPL token and launches it in one instruction. Price and tick range are SOL per token, as in add_to_raydium.
+// `accounts.token_mint_program` must be the legacy token program, and `accounts.symbol` is the new token's symbol.
+// `token_mint` must sign the transaction in addition to signers of LaunchAccounts.
+pub struct CreateAndLaunch {
+    pub accounts: LaunchAccounts,
+    pub amount: u64,
+    pub decimals: u8,
+    pub name: String,
+    pub uri: String,
+    pub sqrt_price_x64: u128,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub token_amount_max: u64,
+    pub wsol_amount_max: u64,
+}
+
+impl CreateAndLaunch {
+    pub fn raydium_accounts(&self) -> Result<RaydiumAccounts> {
+        let (tick_lower_index, tick_upper_index) =
+            pda::pool_tick_range(self.accounts.token_is_mint_a(), self.tick_lower_index, self.tick_upper_index)?;
+        Ok(self.accounts.raydium_accounts(tick_lower_index, tick_upper_index))
+    }
+
+    pub fn instruction(&self) -> Result<Instruction> {
+        let accounts = &self.accounts;
+        Ok(instruction(
+            token_deployer::accounts::CreateAndLaunchAccounts {
+                config: pda::launcher_config(),
+                payer: accounts.payer,
+                token_mint: accounts.token_mint,
+                token_account: get_associated_token_address(&accounts.payer, &accounts.token_mint),
+                launch_record: pda::launch_record(&accounts.token_mint),
+                token_metadata_account: pda::metadata_account(&accounts.token_mint),
+                symbol_registry: pda::symbol_registry(&metadata::normalize_symbol(&accounts.symbol)?),
+                raydium: accounts.raydium_launch_accounts(self.raydium_accounts()?),
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                token_2022_program: token_2022::ID,
+                metadata_program: mpl_token_metadata::ID,
+                rent_program: rent::ID,
+                event_authority: pda::event_authority(),
+                program: token_deployer::ID,
+            },
+            token_deployer::instruction::CreateAndLaunch {
+                amount: self.amount,
+                decimals: self.decimals,
+                name: self.name.clone(),
+                symbol: accounts.symbol.clone(),
+                uri: self.uri.clone(),
+                sqrt_price_x64: self.sqrt_price_x64,
+                tick_lower_index: self.tick_lower_index,
+                tick_upper_index: self.tick_upper_index,
+                liquidity: self.liquidity,
+                token_amount_max: self.token_amount_max,
+                wsol_amount_max: self.wsol_amount_max,
+            },
+        ))
+    }
+}
+
+// Harvests fees of a position. Tick range is in pool orientation, as stored in LaunchRecord.
+// `wsol_recipient_account` must sign the transaction.
+pub struct HarvestFees {
+    pub payer: Pubkey,
+    pub wsol_recipient_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_mint_program: Pubkey,  // Legacy token program or Token-2022
+    pub amm_config: Pubkey,
+    pub tick_spacing: u16,           // Tick spacing of `amm_config`
+    pub position_nft_mint: Pubkey,
+    pub pool_tick_lower_index: i32,
+    pub pool_tick_upper_index: i32,
+    pub creator: Option<Pubkey>,     // None only for positions launched before launch records, whose launch record PDA is empty
+    pub fee_receiver: Pubkey,        // From launcher config
+    pub clmm_program: Pubkey,        // From launcher config
+}
+
+impl HarvestFees {
+    pub fn from_launch_record(
+        launch_record: &LaunchRecord,
+        payer: Pubkey,
+        wsol_recipient_account: Pubkey,
+        token_mint_program: Pubkey,
+        amm_config: Pubkey,
+        tick_spacing: u16,
+        fee_receiver: Pubkey,
+        clmm_program: Pubkey,
+    ) -> Self {
+        Self {
+            payer,
+            wsol_recipient_account,
+            token_mint: launch_record.token_mint,
+            token_mint_program,
+            amm_config,
+            tick_spacing,
+            position_nft_mint: launch_record.position_nft_mint,
+            pool_tick_lower_index: launch_record.tick_lower_index,
+            pool_tick_upper_index: launch_record.tick_upper_index,
+            creator: Some(launch_record.creator),
+            fee_receiver,
+            clmm_program,
+        }
+    }
+
+    pub fn raydium_accounts(&self) -> RaydiumAccounts {
+        RaydiumAccounts::derive(
+            &self.clmm_program,
+            &self.amm_config,
+            self.tick_spacing,
+            &self.token_mint,
+            &native_mint::ID,
+            &self.position_nft_mint,
+            self.pool_tick_lower_index,
+            self.pool_tick_upper_index,
+        )
+    }
+
+    // exTickArrayBitmap is passed to Raydium as a remaining account, so positions outside
+    // of the pool's default tick array bitmap can be harvested as well
+    pub fn instruction(&self) -> Instruction {
+        let raydium = self.raydium_accounts();
+        let creator_escrow = pda::creator_escrow(&self.token_mint);
+        let associated_token_address = |owner: &Pubkey| {
+            get_associated_token_address_with_program_id(owner, &self.token_mint, &self.token_mint_program)
+        };
+        let mut instruction = instruction(
+            token_deployer::accounts::HarvestAccounts {
+                config: pda::launcher_config(),
+                position_nft_owner_pda: pda::position_nft_owner_pda(),
+                position_nft_account: raydium.position_nft_account,
+                position_nft_mint: self.position_nft_mint,
+                launch_record: pda::launch_record(&self.token_mint),
+                creator: self.creator,
+                creator_token_account: self.creator.as_ref().map(associated_token_address),
+                creator_escrow: self.creator.map(|_| creator_escrow),
+                creator_escrow_token_account: self.creator.map(|_| associated_token_address(&creator_escrow)),
+                personal_position: raydium.personal_position,
+                pool_id: raydium.pool_id,
+                protocol_position: raydium.protocol_position,
+                token_vault: raydium.token_vault,
+                wsol_vault: raydium.wsol_vault,
+                tick_array_lower: raydium.tick_array_lower,
+                tick_array_upper: raydium.tick_array_upper,
+                token_recipient_account: associated_token_address(&self.payer),
+                wsol_recipient_account: self.wsol_recipient_account,
+                token_mint: self.token_mint,
+                wsol_mint: native_mint::ID,
+                fee_receiver: self.fee_receiver,
+                fee_receiver_token_account: associated_token_address(&self.fee_receiver),
+                payer: self.payer,
+                clmm_program: self.clmm_program,
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                token_2022_program: token_2022::ID,
+                token_mint_program: self.token_mint_program,
+                memo_program: MEMO_PROGRAM_ID,
+                rent_program: rent::ID,
+                event_authority: pda::event_authority(),
+                program: token_deployer::ID,
+            },
+            token_deployer::instruction::HarvestFees {},
+        );
+        instruction.accounts.push(AccountMeta::new(raydium.ex_tick_array_bitmap, false));
+        instruction
+    }
+}
+
+
+// Position harvested by harvest_many. Tick range is in pool orientation, as stored in LaunchRecord.
+pub struct HarvestPosition {
+    pub token_mint: Pubkey,
+    pub token_mint_program: Pubkey,  // Legacy token program or Token-2022
+    pub amm_config: Pubkey,
+    pub tick_spacing: u16,           // Tick spacing of `amm_config`
+    pub position_nft_mint: Pubkey,
+    pub pool_tick_lower_index: i32,
+    pub pool_tick_upper_index: i32,
+    pub creator: Pubkey,
+}
+
+impl HarvestPosition {
+    pub fn from_launch_record(launch_record: &LaunchRecord, token_mint_program: Pubkey, amm_config: Pubkey, tick_spacing: u16) -> Self {
+        Self {
+            token_mint: launch_record.token_mint,
+            token_mint_program,
+            amm_config,
+            tick_spacing,
+            position_nft_mint: launch_record.position_nft_mint,
+            pool_tick_lower_index: launch_record.tick_lower_index,
+            pool_tick_upper_index: launch_record.tick_upper_index,
+            creator: launch_record.creator,
+        }
+    }
+
+    pub fn raydium_accounts(&self, clmm_program: &Pubkey) -> RaydiumAccounts {
+        RaydiumAccounts::derive(
+            clmm_program,
+            &self.amm_config,
+            self.tick_spacing,
+            &self.token_mint,
+            &native_mint::ID,
+            &self.position_nft_mint,
+            self.pool_tick_lower_index,
+            self.pool_tick_upper_index,
+        )
+    }
+
+    // Position accounts in the order harvest_many reads them, followed by exTickArrayBitmap as
+    // the only Raydium remaining account
+    fn account_metas(&self, payer: &Pubkey, fee_receiver: &Pubkey, clmm_program: &Pubkey) -> Vec<AccountMeta> {
+        let raydium = self.raydium_accounts(clmm_program);
+        let creator_escrow = pda::creator_escrow(&self.token_mint);
+        let associated_token_address = |owner: &Pubkey| {
+            get_associated_token_address_with_program_id(owner, &self.token_mint, &self.token_mint_program)
+        };
+        vec![
+            AccountMeta::new(raydium.position_nft_account, false),
+            AccountMeta::new(raydium.personal_position, false),
+            AccountMeta::new(raydium.pool_id, false),
+            AccountMeta::new(raydium.protocol_position, false),
+            AccountMeta::new(raydium.token_vault, false),
+            AccountMeta::new(raydium.wsol_vault, false),
+            AccountMeta::new(raydium.tick_array_lower, false),
+            AccountMeta::new(raydium.tick_array_upper, false),
+            AccountMeta::new_readonly(self.token_mint, false),
+            AccountMeta::new(associated_token_address(payer), false),
+            AccountMeta::new(associated_token_address(fee_receiver), false),
+            AccountMeta::new(pda::launch_record(&self.token_mint), false),
+            AccountMeta::new(self.creator, false),
+            AccountMeta::new(associated_token_address(&self.creator), false),
+            AccountMeta::new(creator_escrow, false),
+            AccountMeta::new(associated_token_address(&creator_escrow), false),
+            AccountMeta::new(raydium.ex_tick_array_bitmap, false),
+        ]
+    }
+}
+
+// Harvests fees of several launched positions. Payer must hold token accounts of every position's token,
+// and `wsol_recipient_account` must sign the transaction.
+pub struct HarvestMany {
+    pub payer: Pubkey,
+    pub wsol_recipient_account: Pubkey,
+    pub positions: Vec<HarvestPosition>,
+    pub fee_receiver: Pubkey,        // From launcher config
+    pub clmm_program: Pubkey,        // From launcher config
+}
+
+impl HarvestMany {
+    pub fn instruction(&self) -> Instruction {
+        let mut instruction = instruction(
+            token_deployer::accounts::HarvestManyAccounts {
+                config: pda::launcher_config(),
+                position_nft_owner_pda: pda::position_nft_owner_pda(),
+                wsol_recipient_account: self.wsol_recipient_account,
+                wsol_mint: native_mint::ID,
+                fee_receiver: self.fee_receiver,
+                payer: self.payer,
+                clmm_program: self.clmm_program,
+                system_program: system_program::ID,
+                token_program: token::ID,
+                token_2022_program: token_2022::ID,
+                memo_program: MEMO_PROGRAM_ID,
+                rent_program: rent::ID,
+                event_authority: pda::event_authority(),
+                program: token_deployer::ID,
+            },
+            token_deployer::instruction::HarvestMany {
+                raydium_remaining_accounts_counts: vec![1; self.positions.len()],
+            },
+        );
+        for position in &self.positions {
+            instruction.accounts.extend(position.account_metas(&self.payer, &self.fee_receiver, &self.clmm_program));
+        }
+        instruction
+    }
+}
+
+// Harvests Token-2022 withheld transfer fees of a token. `symbol` of the token's metadata is required only when
+// the token was not launched, as its creator is then read from the symbol registry.
+pub struct HarvestWithheldFees {
+    pub payer: Pubkey,
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub symbol: Option<String>,
+    pub token_accounts: Vec<Pubkey>, // Token accounts to collect withheld fees from (such as Raydium vaults)
+    pub fee_receiver: Pubkey,        // From launcher config
+}
+
+impl HarvestWithheldFees {
+    pub fn instruction(&self) -> Result<Instruction> {
+        let symbol_registry = match &self.symbol {
+            Some(symbol) => Some(pda::symbol_registry(&metadata::normalize_symbol(symbol)?)),
+            None => None,
+        };
+        let associated_token_address =
+            |owner: &Pubkey| get_associated_token_address_with_program_id(owner, &self.token_mint, &token_2022::ID);
+        let withheld_fee_authority = pda::withheld_fee_authority();
+        let mut instruction = instruction(
+            token_deployer::accounts::HarvestWithheldFeesAccounts {
+                config: pda::launcher_config(),
+                token_mint: self.token_mint,
+                launch_record: pda::launch_record(&self.token_mint),
+                symbol_registry,
+                withheld_fee_authority,
+                withheld_fee_vault: associated_token_address(&withheld_fee_authority),
+                creator: self.creator,
+                creator_token_account: associated_token_address(&self.creator),
+                fee_receiver: self.fee_receiver,
+                fee_receiver_token_account: associated_token_address(&self.fee_receiver),
+                payer: self.payer,
+                system_program: system_program::ID,
+                token_2022_program: token_2022::ID,
+                associated_token_program: associated_token::ID,
+                event_authority: pda::event_authority(),
+                program: token_deployer::ID,
+            },
+            token_deployer::instruction::HarvestWithheldFees {},
+        );
+        instruction.accounts.extend(self.token_accounts.iter().map(|account| AccountMeta::new(*account, false)));
+        Ok(instruction)
+    }
+}
+
+// Claims creator's fees held in escrow. `creator` must sign the transaction.
+pub struct ClaimCreatorFees {
+    pub creator: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_mint_program: Pubkey,  // Legacy token program or Token-2022
+    pub escrow_holds_tokens: bool,   // Whether escrow's token account exists, it is only created for token shares
+}
+
+impl ClaimCreatorFees {
+    pub fn instruction(&self) -> Instruction {
+        let creator_escrow = pda::creator_escrow(&self.token_mint);
+        let associated_token_address = |owner: &Pubkey| {
+            get_associated_token_address_with_program_id(owner, &self.token_mint, &self.token_mint_program)
+        };
+        instruction(
+            token_deployer::accounts::ClaimCreatorFeesAccounts {
+                creator: self.creator,
+                token_mint: self.token_mint,
+                creator_escrow,
+                creator_escrow_token_account: self.escrow_holds_tokens.then(|| associated_token_address(&creator_escrow)),
+                creator_token_account: associated_token_address(&self.creator),
+                system_program: system_program::ID,
+                token_program: self.token_mint_program,
+                associated_token_program: associated_token::ID,
+            },
+            token_deployer::instruction::ClaimCreatorFees {},
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+    use solana_program::pubkey;
+
+    const CLMM_PROGRAM_ID: Pubkey = pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+    const AMM_CONFIG: Pubkey = Pubkey::new_from_array([7; 32]);
+    const TOKEN_MINT: Pubkey = Pubkey::new_from_array([200; 32]);  // Sorts after WSOL
+    const POSITION_NFT_MINT: Pubkey = Pubkey::new_from_array([9; 32]);
+    const PAYER: Pubkey = Pubkey::new_from_array([3; 32]);
+    const CREATOR: Pubkey = Pubkey::new_from_array([4; 32]);
+    const FEE_RECEIVER: Pubkey = Pubkey::new_from_array([5; 32]);
+
+    fn launch_accounts() -> LaunchAccounts {
+        LaunchAccounts {
+            payer: PAYER,
+            token_mint: TOKEN_MINT,
+            token_mint_program: token::ID,
+            symbol: "rkt".to_string(),
+            amm_config: AMM_CONFIG,
+            tick_spacing: 60,
+            observation: Pubkey::new_from_array([10; 32]),
+            position_nft_mint: POSITION_NFT_MINT,
+            wsol_account: Pubkey::new_from_array([11; 32]),
+            fee_receiver: FEE_RECEIVER,
+            clmm_program: CLMM_PROGRAM_ID,
+        }
+    }
+
+    fn add_to_raydium() -> AddToRaydium {
+        AddToRaydium {
+            accounts: launch_accounts(),
+            sqrt_price_x64: 1 << 64,
+            tick_lower_index: -3540,
+            tick_upper_index: 3600,
+            liquidity: 1,
+            token_amount_max: 1,
+            wsol_amount_max: 1,
+        }
+    }
+
+    #[test]
+    fn derives_add_to_raydium_accounts_in_pool_orientation() {
+        // Token is side B, so the pool's tick range is [-3600, 3540]
+        let raydium = add_to_raydium().raydium_accounts().unwrap();
+        let pool_id = pda::pool(&CLMM_PROGRAM_ID, &AMM_CONFIG, &native_mint::ID, &TOKEN_MINT);
+        assert_eq!(raydium.pool_id, pool_id);
+        assert_eq!(raydium.protocol_position, pda::protocol_position(&CLMM_PROGRAM_ID, &pool_id, -3600, 3540));
+        assert_eq!(raydium.tick_array_lower, pda::tick_array(&CLMM_PROGRAM_ID, &pool_id, -3600));
+        assert_eq!(raydium.tick_array_upper, pda::tick_array(&CLMM_PROGRAM_ID, &pool_id, 0));
+    }
+
+    #[test]
+    fn builds_add_to_raydium_instruction() {
+        let builder = add_to_raydium();
+        let instruction = builder.instruction().unwrap();
+        let raydium = builder.raydium_accounts().unwrap();
+        let keys: Vec<Pubkey> = instruction.accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(instruction.program_id, token_deployer::ID);
+        assert_eq!(keys[0], pda::launcher_config());
+        assert_eq!(keys[3], get_associated_token_address(&PAYER, &TOKEN_MINT));
+        assert_eq!(keys[4], pda::metadata_account(&TOKEN_MINT));
+        assert_eq!(keys[5], pda::symbol_registry("RKT"));
+        assert_eq!(keys[6], pda::launch_record(&TOKEN_MINT));
+        assert_eq!(keys[8], raydium.pool_id);
+        assert_eq!(keys[16], raydium.position_nft_account);
+        assert!(instruction.accounts[14].is_signer);  // position_nft_mint
+        assert!(instruction.accounts[22].is_signer);  // wsol_account
+        // Ticks are passed as SOL per token, the program converts them to pool orientation itself
+        assert_eq!(&instruction.data[..8], &token_deployer::instruction::AddToRaydium::DISCRIMINATOR);
+        assert_eq!(&instruction.data[24..32], &[0x2c, 0xf2, 0xff, 0xff, 0x10, 0x0e, 0, 0]);
+    }
+
+    #[test]
+    fn add_to_raydium_by_market_cap_uses_computed_position() {
+        let builder = AddToRaydiumByMarketCap {
+            accounts: launch_accounts(),
+            supply: 1_000_000_000_000_000,
+            initial_fdv_lamports: 10_000_000_000,
+            upper_fdv_lamports: 1_000_000_000_000,
+            token_amount: 1_000_000_000_000_000,
+        };
+        let position = builder.position().unwrap();
+        let raydium = builder.raydium_accounts().unwrap();
+        assert_eq!(
+            raydium.protocol_position,
+            pda::protocol_position(&CLMM_PROGRAM_ID, &raydium.pool_id, position.tick_lower_index, position.tick_upper_index)
+        );
+        let instruction = builder.instruction().unwrap();
+        assert_eq!(instruction.accounts[18].pubkey, raydium.protocol_position);
+        assert_eq!(&instruction.data[..8], &token_deployer::instruction::AddToRaydiumByMarketCap::DISCRIMINATOR);
+    }
+
+    #[test]
+    fn harvest_fees_uses_launch_record_accounts() {
+        let launch_record = LaunchRecord {
+            creator: CREATOR,
+            token_mint: TOKEN_MINT,
+            pool_id: pda::pool(&CLMM_PROGRAM_ID, &AMM_CONFIG, &native_mint::ID, &TOKEN_MINT),
+            position_nft_mint: POSITION_NFT_MINT,
+            token_is_mint_a: false,
+            tick_lower_index: -3600,
+            tick_upper_index: 3540,
+            initial_sqrt_price_x64: 1 << 64,
+            liquidity: 1,
+            launch_slot: 0,
+            launch_timestamp: 0,
+            token_harvested: 0,
+            wsol_harvested: 0,
+            bump: 255,
+        };
+        let builder = HarvestFees::from_launch_record(
+            &launch_record,
+            PAYER,
+            Pubkey::new_from_array([12; 32]),
+            token_2022::ID,
+            AMM_CONFIG,
+            60,
+            FEE_RECEIVER,
+            CLMM_PROGRAM_ID,
+        );
+        let raydium = builder.raydium_accounts();
+        assert_eq!(raydium.pool_id, launch_record.pool_id);
+        // Raydium accounts derived for launch match the ones derived for harvest
+        assert_eq!(raydium, add_to_raydium().raydium_accounts().unwrap());
+
+        let instruction = builder.instruction();
+        let keys: Vec<Pubkey> = instruction.accounts.iter().map(|meta| meta.pubkey).collect();
+        let creator_escrow = pda::creator_escrow(&TOKEN_MINT);
+        assert_eq!(keys[4], pda::launch_record(&TOKEN_MINT));
+        assert_eq!(keys[5], CREATOR);
+        assert_eq!(keys[6], get_associated_token_address_with_program_id(&CREATOR, &TOKEN_MINT, &token_2022::ID));
+        assert_eq!(keys[7], creator_escrow);
+        assert_eq!(keys[8], get_associated_token_address_with_program_id(&creator_escrow, &TOKEN_MINT, &token_2022::ID));
+        assert_eq!(keys.last(), Some(&raydium.ex_tick_array_bitmap));
+
+        // Without creator optional accounts are replaced with the program ID, launch record PDA is still passed
+        let legacy = HarvestFees { creator: None, ..builder };
+        let keys: Vec<Pubkey> = legacy.instruction().accounts.iter().map(|meta| meta.pubkey).collect();
+        assert_eq!(keys[4], pda::launch_record(&TOKEN_MINT));
+        assert!(keys[5..9].iter().all(|key| key == &token_deployer::ID));
+    }
+
+    #[test]
+    fn create_and_launch_derives_accounts_like_add_to_raydium() {
+        let add_to_raydium = add_to_raydium();
+        let builder = CreateAndLaunch {
+            accounts: launch_accounts(),
+            amount: 1_000_000_000,
+            decimals: 9,
+            name: "Rocket".to_string(),
+            uri: "https://example.com/rkt.json".to_string(),
+            sqrt_price_x64: add_to_raydium.sqrt_price_x64,
+            tick_lower_index: add_to_raydium.tick_lower_index,
+            tick_upper_index: add_to_raydium.tick_upper_index,
+            liquidity: 1,
+            token_amount_max: 1,
+            wsol_amount_max: 1,
+        };
+        let raydium = builder.raydium_accounts().unwrap();
+        assert_eq!(raydium, add_to_raydium.raydium_accounts().unwrap());
+        let instruction = builder.instruction().unwrap();
+        let signers: Vec<Pubkey> =
+            instruction.accounts.iter().filter(|meta| meta.is_signer).map(|meta| meta.pubkey).collect();
+        assert_eq!(signers, vec![PAYER, TOKEN_MINT, POSITION_NFT_MINT, Pubkey::new_from_array([11; 32])]);
+        assert!(instruction.accounts.iter().any(|meta| meta.pubkey == pda::symbol_registry("RKT")));
+        assert!(instruction.accounts.iter().any(|meta| meta.pubkey == raydium.protocol_position));
+        assert_eq!(&instruction.data[..8], &token_deployer::instruction::CreateAndLaunch::DISCRIMINATOR);
+    }
+
+    #[test]
+    fn builds_harvest_many_position_groups() {
+        let position = |token_mint| HarvestPosition {
+            token_mint,
+            token_mint_program: token::ID,
+            amm_config: AMM_CONFIG,
+            tick_spacing: 60,
+            position_nft_mint: POSITION_NFT_MINT,
+            pool_tick_lower_index: -3600,
+            pool_tick_upper_index: 3540,
+            creator: CREATOR,
+        };
+        let builder = HarvestMany {
+            payer: PAYER,
+            wsol_recipient_account: Pubkey::new_from_array([12; 32]),
+            positions: vec![position(TOKEN_MINT), position(Pubkey::new_from_array([1; 32]))],
+            fee_receiver: FEE_RECEIVER,
+            clmm_program: CLMM_PROGRAM_ID,
+        };
+        let instruction = builder.instruction();
+        // 14 shared accounts, then POSITION_ACCOUNTS_LEN accounts and exTickArrayBitmap of each position
+        let group_len = 16 + 1;
+        assert_eq!(instruction.accounts.len(), 14 + 2 * group_len);
+        assert_eq!(&instruction.data[8..], &[2, 0, 0, 0, 1, 1]);
+        for (index, position) in builder.positions.iter().enumerate() {
+            let group = &instruction.accounts[14 + index * group_len..][..group_len];
+            let raydium = position.raydium_accounts(&CLMM_PROGRAM_ID);
+            assert_eq!(group[0].pubkey, raydium.position_nft_account);
+            assert_eq!(group[8].pubkey, position.token_mint);
+            assert_eq!(group[11].pubkey, pda::launch_record(&position.token_mint));
+            assert_eq!(group[12].pubkey, CREATOR);
+            assert_eq!(group[14].pubkey, pda::creator_escrow(&position.token_mint));
+            assert_eq!(group[16].pubkey, raydium.ex_tick_array_bitmap);
+        }
+    }
+
+    #[test]
+    fn omits_optional_creator_accounts() {
+        // Symbol registry is only passed for tokens that were not launched
+        let harvest_withheld_fees = HarvestWithheldFees {
+            payer: PAYER,
+            token_mint: TOKEN_MINT,
+            creator: CREATOR,
+            symbol: None,
+            token_accounts: vec![Pubkey::new_from_array([13; 32])],
+            fee_receiver: FEE_RECEIVER,
+        };
+        let instruction = harvest_withheld_fees.instruction().unwrap();
+        assert_eq!(instruction.accounts[2].pubkey, pda::launch_record(&TOKEN_MINT));
+        assert_eq!(instruction.accounts[3].pubkey, token_deployer::ID);
+        assert_eq!(instruction.accounts.last().unwrap().pubkey, Pubkey::new_from_array([13; 32]));
+        let instruction = HarvestWithheldFees { symbol: Some("rkt".to_string()), ..harvest_withheld_fees }.instruction().unwrap();
+        assert_eq!(instruction.accounts[3].pubkey, pda::symbol_registry("RKT"));
+
+        // Escrow token account is only passed when escrow received token shares
+        let claim_creator_fees = ClaimCreatorFees {
+            creator: CREATOR,
+            token_mint: TOKEN_MINT,
+            token_mint_program: token_2022::ID,
+            escrow_holds_tokens: false,
+        };
+        let creator_escrow = pda::creator_escrow(&TOKEN_MINT);
+        assert_eq!(claim_creator_fees.instruction().accounts[3].pubkey, token_deployer::ID);
+        let instruction = ClaimCreatorFees { escrow_holds_tokens: true, ..claim_creator_fees }.instruction();
+        assert_eq!(
+            instruction.accounts[3].pubkey,
+            get_associated_token_address_with_program_id(&creator_escrow, &TOKEN_MINT, &token_2022::ID)
+        );
+    }
+
+    #[test]
+    fn derives_symbol_registry_of_normalized_symbol() {
+        let reserve = ReserveSymbol { admin: PAYER, symbol: " rkt ".to_string() }.instruction().unwrap();
+        let release = ReleaseSymbol { admin: PAYER, symbol: "RKT".to_string() }.instruction().unwrap();
+        assert_eq!(reserve.accounts[1].pubkey, pda::symbol_registry("RKT"));
+        assert_eq!(release.accounts[1].pubkey, pda::symbol_registry("RKT"));
+        assert!(ReserveSymbol { admin: PAYER, symbol: String::new() }.instruction().is_err());
+    }
+}
@@ -0,0 +1,9 @@
+#![allow(clippy::too_many_arguments)]
+
+// Off-chain helpers for token_deployer: instruction builders and derivations of the program's
+// and Raydium CLMM accounts
+pub mod instructions;
+pub mod pda;
+
+pub use instructions::*;
+pub use token_deployer;
@@ -0,0 +1,263 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_spl::metadata::mpl_token_metadata;
+use token_deployer::math;
+use token_deployer::raydium;
+use token_deployer::state::{
+    CREATOR_ESCROW_SEED, LAUNCHER_CONFIG_SEED, LAUNCH_RECORD_SEED, SYMBOL_REGISTRY_SEED, WITHHELD_FEE_AUTHORITY_SEED,
+};
+
+// token_deployer seeds not exported by the program:
+pub const POSITION_NFT_OWNER_SEED: &[u8] = b"position_nft_owner_pda";
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+// Raydium CLMM seeds, the rest are shared with the program's address checks:
+pub const AMM_CONFIG_SEED: &[u8] = b"amm_config";
+pub use token_deployer::raydium::{
+    POOL_SEED, POOL_TICK_ARRAY_BITMAP_SEED, POOL_VAULT_SEED, POSITION_SEED, TICK_ARRAY_SEED,
+};
+
+// Metaplex metadata seed:
+pub const METADATA_SEED: &[u8] = b"metadata";
+
+pub fn position_nft_owner_pda() -> Pubkey {
+    Pubkey::find_program_address(&[POSITION_NFT_OWNER_SEED], &token_deployer::ID).0
+}
+
+pub fn event_authority() -> Pubkey {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &token_deployer::ID).0
+}
+
+pub fn launcher_config() -> Pubkey {
+    Pubkey::find_program_address(&[LAUNCHER_CONFIG_SEED], &token_deployer::ID).0
+}
+
+pub fn launch_record(token_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[LAUNCH_RECORD_SEED, token_mint.as_ref()], &token_deployer::ID).0
+}
+
+pub fn creator_escrow(token_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CREATOR_ESCROW_SEED, token_mint.as_ref()], &token_deployer::ID).0
+}
+
+pub fn withheld_fee_authority() -> Pubkey {
+    Pubkey::find_program_address(&[WITHHELD_FEE_AUTHORITY_SEED], &token_deployer::ID).0
+}
+
+// `symbol` must already be normalized (see token_deployer::metadata::normalize_symbol)
+pub fn symbol_registry(symbol: &str) -> Pubkey {
+    Pubkey::find_program_address(&[SYMBOL_REGISTRY_SEED, symbol.as_bytes()], &token_deployer::ID).0
+}
+
+// ProgramData account of the upgradeable token_deployer program
+pub fn program_data() -> Pubkey {
+    Pubkey::find_program_address(&[token_deployer::ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+pub fn metadata_account(mint: &Pubkey) -> Pubkey {
+    let metadata_program_id = mpl_token_metadata::ID;
+    Pubkey::find_program_address(&[METADATA_SEED, metadata_program_id.as_ref(), mint.as_ref()], &metadata_program_id).0
+}
+
+pub fn amm_config(clmm_program_id: &Pubkey, index: u16) -> Pubkey {
+    Pubkey::find_program_address(&[AMM_CONFIG_SEED, &index.to_be_bytes()], clmm_program_id).0
+}
+
+// Mints must be sorted (mint_a < mint_b), see token_is_mint_a
+pub fn pool(clmm_program_id: &Pubkey, amm_config: &Pubkey, mint_a: &Pubkey, mint_b: &Pubkey) -> Pubkey {
+    raydium::pool_address(clmm_program_id, amm_config, mint_a, mint_b)
+}
+
+pub fn pool_vault(clmm_program_id: &Pubkey, pool: &Pubkey, mint: &Pubkey) -> Pubkey {
+    raydium::pool_vault_address(clmm_program_id, pool, mint)
+}
+
+pub fn tick_array_bitmap_extension(clmm_program_id: &Pubkey, pool: &Pubkey) -> Pubkey {
+    raydium::tick_array_bitmap_extension_address(clmm_program_id, pool)
+}
+
+// Ticks are in pool orientation
+pub fn protocol_position(clmm_program_id: &Pubkey, pool: &Pubkey, tick_lower_index: i32, tick_upper_index: i32) -> Pubkey {
+    raydium::protocol_position_address(clmm_program_id, pool, tick_lower_index, tick_upper_index)
+}
+
+pub fn personal_position(clmm_program_id: &Pubkey, position_nft_mint: &Pubkey) -> Pubkey {
+    raydium::personal_position_address(clmm_program_id, position_nft_mint)
+}
+
+pub fn tick_array(clmm_program_id: &Pubkey, pool: &Pubkey, start_index: i32) -> Pubkey {
+    raydium::tick_array_address(clmm_program_id, pool, start_index)
+}
+
+// Raydium CLMM requires mint_a < mint_b, so the token is side A only if it sorts before WSOL
+pub fn token_is_mint_a(token_mint: &Pubkey, wsol_mint: &Pubkey) -> bool {
+    token_deployer::token_is_mint_a(token_mint, wsol_mint)
+}
+
+// Converts tick range given as SOL per token to pool orientation, as add_to_raydium does on-chain
+pub fn pool_tick_range(token_is_mint_a: bool, tick_lower_index: i32, tick_upper_index: i32) -> Result<(i32, i32)> {
+    if token_is_mint_a {
+        Ok((tick_lower_index, tick_upper_index))
+    } else {
+        math::invert_tick_range(tick_lower_index, tick_upper_index)
+    }
+}
+
+// Raydium accounts of a TOKEN/WSOL pool and a position in it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RaydiumAccounts {
+    pub token_is_mint_a: bool,
+    pub pool_id: Pubkey,
+    pub token_vault: Pubkey,
+    pub wsol_vault: Pubkey,
+    pub ex_tick_array_bitmap: Pubkey,
+    pub protocol_position: Pubkey,
+    pub tick_array_lower: Pubkey,
+    pub tick_array_upper: Pubkey,
+    pub personal_position: Pubkey,
+    pub position_nft_account: Pubkey,
+    pub position_nft_metadata: Pubkey,
+}
+
+impl RaydiumAccounts {
+    // Derives all accounts from the mints, AMM config and position's tick range in pool orientation.
+    // Position NFT is held by the program's position_nft_owner_pda.
+    pub fn derive(
+        clmm_program_id: &Pubkey,
+        amm_config: &Pubkey,
+        tick_spacing: u16,
+        token_mint: &Pubkey,
+        wsol_mint: &Pubkey,
+        position_nft_mint: &Pubkey,
+        pool_tick_lower_index: i32,
+        pool_tick_upper_index: i32,
+    ) -> Self {
+        let token_is_mint_a = token_is_mint_a(token_mint, wsol_mint);
+        let (mint_a, mint_b) = if token_is_mint_a { (token_mint, wsol_mint) } else { (wsol_mint, token_mint) };
+        let pool_id = pool(clmm_program_id, amm_config, mint_a, mint_b);
+        let tick_array_lower_start_index = math::tick_array_start_index(pool_tick_lower_index, tick_spacing);
+        let tick_array_upper_start_index = math::tick_array_start_index(pool_tick_upper_index, tick_spacing);
+        Self {
+            token_is_mint_a,
+            pool_id,
+            token_vault: pool_vault(clmm_program_id, &pool_id, token_mint),
+            wsol_vault: pool_vault(clmm_program_id, &pool_id, wsol_mint),
+            ex_tick_array_bitmap: tick_array_bitmap_extension(clmm_program_id, &pool_id),
+            protocol_position: protocol_position(clmm_program_id, &pool_id, pool_tick_lower_index, pool_tick_upper_index),
+            tick_array_lower: tick_array(clmm_program_id, &pool_id, tick_array_lower_start_index),
+            tick_array_upper: tick_array(clmm_program_id, &pool_id, tick_array_upper_start_index),
+            personal_position: personal_position(clmm_program_id, position_nft_mint),
+            position_nft_account: anchor_spl::associated_token::get_associated_token_address(
+                &position_nft_owner_pda(),
+                position_nft_mint,
+            ),
+            position_nft_metadata: metadata_account(position_nft_mint),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_spl::token::spl_token::native_mint;
+    use solana_program::pubkey;
+
+    const CLMM_PROGRAM_ID: Pubkey = pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+    const AMM_CONFIG: Pubkey = Pubkey::new_from_array([7; 32]);
+    const TOKEN_MINT_A: Pubkey = Pubkey::new_from_array([1; 32]);  // Sorts before WSOL
+    const TOKEN_MINT_B: Pubkey = Pubkey::new_from_array([200; 32]);  // Sorts after WSOL
+    const POSITION_NFT_MINT: Pubkey = Pubkey::new_from_array([9; 32]);
+
+    #[test]
+    fn orders_mints_like_raydium() {
+        assert!(token_is_mint_a(&TOKEN_MINT_A, &native_mint::ID));
+        assert!(!token_is_mint_a(&TOKEN_MINT_B, &native_mint::ID));
+        assert_eq!(pool_tick_range(true, -1200, 600).unwrap(), (-1200, 600));
+        assert_eq!(pool_tick_range(false, -1200, 600).unwrap(), (-600, 1200));
+        assert!(pool_tick_range(false, i32::MIN, 0).is_err());
+    }
+
+    #[test]
+    fn encodes_seeds_like_raydium() {
+        let pool_id = pool(&CLMM_PROGRAM_ID, &AMM_CONFIG, &TOKEN_MINT_A, &native_mint::ID);
+        // Tick indices are encoded big-endian, so negative ticks must not be confused with their absolute values
+        assert_eq!(
+            protocol_position(&CLMM_PROGRAM_ID, &pool_id, -120, 120),
+            Pubkey::find_program_address(
+                &[b"position", pool_id.as_ref(), &[0xff, 0xff, 0xff, 0x88], &[0, 0, 0, 120]],
+                &CLMM_PROGRAM_ID,
+            ).0
+        );
+        assert_eq!(
+            tick_array(&CLMM_PROGRAM_ID, &pool_id, -600),
+            Pubkey::find_program_address(&[b"tick_array", pool_id.as_ref(), &[0xff, 0xff, 0xfd, 0xa8]], &CLMM_PROGRAM_ID).0
+        );
+        assert_eq!(
+            amm_config(&CLMM_PROGRAM_ID, 1),
+            Pubkey::find_program_address(&[b"amm_config", &[0, 1]], &CLMM_PROGRAM_ID).0
+        );
+        assert_eq!(
+            metadata_account(&POSITION_NFT_MINT),
+            Pubkey::find_program_address(
+                &[b"metadata", mpl_token_metadata::ID.as_ref(), POSITION_NFT_MINT.as_ref()],
+                &mpl_token_metadata::ID,
+            ).0
+        );
+    }
+
+    #[test]
+    fn derives_raydium_accounts_for_token_side_a() {
+        let accounts = RaydiumAccounts::derive(
+            &CLMM_PROGRAM_ID, &AMM_CONFIG, 10, &TOKEN_MINT_A, &native_mint::ID, &POSITION_NFT_MINT, -610, 600,
+        );
+        let pool_id = pool(&CLMM_PROGRAM_ID, &AMM_CONFIG, &TOKEN_MINT_A, &native_mint::ID);
+        assert!(accounts.token_is_mint_a);
+        assert_eq!(accounts.pool_id, pool_id);
+        assert_eq!(accounts.token_vault, pool_vault(&CLMM_PROGRAM_ID, &pool_id, &TOKEN_MINT_A));
+        assert_eq!(accounts.wsol_vault, pool_vault(&CLMM_PROGRAM_ID, &pool_id, &native_mint::ID));
+        assert_eq!(accounts.protocol_position, protocol_position(&CLMM_PROGRAM_ID, &pool_id, -610, 600));
+        // 600 ticks per array with spacing of 10
+        assert_eq!(accounts.tick_array_lower, tick_array(&CLMM_PROGRAM_ID, &pool_id, -1200));
+        assert_eq!(accounts.tick_array_upper, tick_array(&CLMM_PROGRAM_ID, &pool_id, 600));
+        assert_eq!(accounts.personal_position, personal_position(&CLMM_PROGRAM_ID, &POSITION_NFT_MINT));
+    }
+
+    #[test]
+    fn derives_raydium_accounts_for_token_side_b() {
+        let accounts = RaydiumAccounts::derive(
+            &CLMM_PROGRAM_ID, &AMM_CONFIG, 60, &TOKEN_MINT_B, &native_mint::ID, &POSITION_NFT_MINT, -3600, 3540,
+        );
+        // Pool is keyed by sorted mints, so WSOL comes first
+        let pool_id = pool(&CLMM_PROGRAM_ID, &AMM_CONFIG, &native_mint::ID, &TOKEN_MINT_B);
+        assert!(!accounts.token_is_mint_a);
+        assert_eq!(accounts.pool_id, pool_id);
+        assert_eq!(accounts.token_vault, pool_vault(&CLMM_PROGRAM_ID, &pool_id, &TOKEN_MINT_B));
+        assert_eq!(accounts.tick_array_lower, tick_array(&CLMM_PROGRAM_ID, &pool_id, -3600));
+        assert_eq!(accounts.tick_array_upper, tick_array(&CLMM_PROGRAM_ID, &pool_id, 0));
+    }
+
+    // Guards against accidental changes of seeds or their encoding
+    #[test]
+    fn pins_derived_addresses() {
+        let accounts = RaydiumAccounts::derive(
+            &CLMM_PROGRAM_ID, &AMM_CONFIG, 60, &TOKEN_MINT_B, &native_mint::ID, &POSITION_NFT_MINT, -3600, 3540,
+        );
+        let pinned = [
+            (accounts.pool_id, "CrutBFipH4V9UL9twignaHTMZhQw2AJBpcz3pCzRooyb"),
+            (accounts.token_vault, "5opdygz2qWsytw9gZ5PrZ7HdkxQCR3vBc8MB1FnLyrKh"),
+            (accounts.wsol_vault, "4p8yoQhsNbXXofrd2rToCidBVMAYxLJZ1fkXsDsroCVN"),
+            (accounts.ex_tick_array_bitmap, "5iP3gbeaHjs77gNvu5q2nBUX8CvqQGiomyjdHMSvx5YN"),
+            (accounts.protocol_position, "GJZKApEqmS4fKUdFdnGErN2nfCayqkVBahKanwabrM41"),
+            (accounts.tick_array_lower, "2rXJqWLuWeMVjX3LEYeFgVxgp9qNXYRr8kcpjkRDXGHH"),
+            (accounts.tick_array_upper, "2XvoyQzmjYEBL1aASTDQxy8vYSrVXLHLs4KBJ2RZJK9j"),
+            (accounts.personal_position, "3ZrzwP1RBKnyfLkqwv4zMruAHE1dFj8UJqmKg77pDqSL"),
+            (accounts.position_nft_account, "EVhMZBCqHbLG6jQGWhG3McLNgzWUxf4fZmgnCdev9q7o"),
+            (accounts.position_nft_metadata, "3ThWX3CcZ4VKueas6QL9rfQ2yjY5PTFWH5sGhnrNchUu"),
+            (launch_record(&TOKEN_MINT_B), "J7rDBj6B7G3VKuofQyRxTst8PzNLW4N7TPTEB74JwF5z"),
+            (symbol_registry("RKT"), "6rZ1dgfbDPJyDC1BmX13CSUkYMBNUS4ZCrRAUYzHTTcH"),
+        ];
+        for (address, expected) in pinned {
+            assert_eq!(address.to_string(), expected);
+        }
+    }
+}
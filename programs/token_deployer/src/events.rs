@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+// Events are emitted with emit_cpi! so they are recorded in inner instructions rather than logs,
+// which can be truncated
+
+#[event]
+pub struct TokenCreated {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub supply: u64,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct LaunchCompleted {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub pool_id: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub token_is_mint_a: bool,      // Pool values below are in pool orientation (price of side A in side B)
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub token_deposited: u64,       // Tokens actually deposited into position
+    pub wsol_deposited: u64,        // WSOL actually deposited into position
+    pub fee_paid: u64,              // Launch fee in lamports
+}
+
+#[event]
+pub struct WithheldFeesHarvested {
+    pub token_mint: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,                // Withheld transfer fees withdrawn from the mint
+    pub creator_share: u64,
+    pub protocol_share: u64,
+}
+
+#[event]
+pub struct FeesHarvested {
+    pub token_mint: Pubkey,
+    pub pool_id: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub token_harvested: u64,
+    pub wsol_harvested: u64,
+    pub creator_token_share: u64,   // Included in harvested amounts above
+    pub creator_wsol_share: u64,
+}
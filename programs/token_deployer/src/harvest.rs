@@ -0,0 +1,573 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount};
+use anchor_spl::associated_token::{get_associated_token_address, get_associated_token_address_with_program_id};
+use anchor_spl::token::{self, CloseAccount, InitializeAccount};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TransferChecked};
+
+use crate::events::FeesHarvested;
+use crate::launch;
+use crate::math;
+use crate::raydium;
+use crate::state::{CreatorEscrow, LaunchRecord, CREATOR_ESCROW_SEED, LAUNCH_RECORD_SEED};
+use crate::{CustomError, BPS_DENOMINATOR, TOKEN_ACCOUNT_SPACE};
+
+// Number of fixed accounts per position passed to harvest_many in remaining accounts:
+//  0. position_nft_account         - position NFT account of position_nft_owner_pda
+//  1. personal_position
+//  2. pool_id
+//  3. protocol_position
+//  4. token_vault
+//  5. wsol_vault
+//  6. tick_array_lower
+//  7. tick_array_upper
+//  8. token_mint
+//  9. token_recipient_account      - payer's token account, must exist
+// 10. fee_receiver_token_account   - fee receiver's associated token account, must exist
+// 11. launch_record
+// 12. creator
+// 13. creator_token_account        - creator's associated token account, may be uninitialized
+// 14. creator_escrow               - must exist when creator's accounts do not
+// 15. creator_escrow_token_account - must exist when creator's token account does not
+// followed by the position's optional Raydium remaining accounts (exTickArrayBitmap and reward accounts).
+pub const POSITION_ACCOUNTS_LEN: usize = 16;
+
+// Accounts shared by all positions harvested in one instruction
+pub struct HarvestCommonAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub position_nft_owner_pda: AccountInfo<'info>,
+    pub wsol_recipient_account: AccountInfo<'info>,
+    pub wsol_mint: AccountInfo<'info>,
+    pub fee_receiver: AccountInfo<'info>,
+    pub clmm_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,  // Legacy token program of WSOL
+    pub token_2022_program: AccountInfo<'info>,
+    pub memo_program: AccountInfo<'info>,
+    pub rent_program: AccountInfo<'info>,
+}
+
+// Amounts harvested from one position of harvest_many, creator's WSOL share is still to be paid
+pub struct PositionHarvest<'info> {
+    pub event: FeesHarvested,
+    pub creator_wsol_destination: AccountInfo<'info>,  // Creator, or creator's escrow when creator does not exist
+}
+
+// Share of `amount` in basis points, rounded down
+pub fn fee_share(amount: u64, share_bps: u16) -> u64 {
+    (amount as u128 * share_bps as u128 / BPS_DENOMINATOR as u128) as u64
+}
+
+// Tokens received by a recipient account during harvest
+pub fn amount_harvested(balance_at_start: u64, balance_at_end: u64) -> Result<u64> {
+    let amount_harvested = balance_at_end.checked_sub(balance_at_start).ok_or(CustomError::ArithmeticOverflow)?;
+    Ok(amount_harvested)
+}
+
+// Part of harvested `amount` left after `share` is paid out of it
+pub fn amount_after_share(amount: u64, share: u64) -> Result<u64> {
+    let amount_after_share = amount.checked_sub(share).ok_or(CustomError::ArithmeticOverflow)?;
+    Ok(amount_after_share)
+}
+
+// Adds harvested amounts to launch record totals
+pub fn record_harvest(launch_record: &mut LaunchRecord, token_harvested: u64, wsol_harvested: u64) -> Result<()> {
+    launch_record.token_harvested =
+        launch_record.token_harvested.checked_add(token_harvested).ok_or(CustomError::ArithmeticOverflow)?;
+    launch_record.wsol_harvested =
+        launch_record.wsol_harvested.checked_add(wsol_harvested).ok_or(CustomError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+// Creates and initializes temporary WSOL account owned by payer to receive harvested WSOL
+pub fn create_wsol_account(accounts: &HarvestCommonAccounts<'_>) -> Result<()> {
+    system_program::create_account(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            CreateAccount {
+                from: accounts.payer.clone(),
+                to: accounts.wsol_recipient_account.clone(),
+            },
+        ),
+        Rent::get()?.minimum_balance(TOKEN_ACCOUNT_SPACE as usize),
+        TOKEN_ACCOUNT_SPACE,
+        accounts.token_program.key,
+    )?;
+    token::initialize_account(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            InitializeAccount {
+                account: accounts.wsol_recipient_account.clone(),
+                mint: accounts.wsol_mint.clone(),
+                authority: accounts.payer.clone(),
+                rent: accounts.rent_program.clone(),
+            }
+        )
+    )
+}
+
+// Closes temporary WSOL account, unwrapping harvested WSOL to payer
+pub fn close_wsol_account(accounts: &HarvestCommonAccounts<'_>) -> Result<()> {
+    token::close_account(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            CloseAccount {
+                account: accounts.wsol_recipient_account.clone(),
+                destination: accounts.payer.clone(),
+                authority: accounts.payer.clone(),
+            },
+        )
+    )
+}
+
+// Collects fees of a position owned by position_nft_owner_pda into token and WSOL recipient accounts.
+// Pool sides are ordered by mint address.
+pub fn harvest_position<'info>(
+    accounts: &HarvestCommonAccounts<'info>,
+    position_nft_owner_bump: u8,
+    position_nft_account: &AccountInfo<'info>,
+    personal_position: &AccountInfo<'info>,
+    pool_id: &AccountInfo<'info>,
+    protocol_position: &AccountInfo<'info>,
+    token_vault: &AccountInfo<'info>,
+    wsol_vault: &AccountInfo<'info>,
+    tick_array_lower: &AccountInfo<'info>,
+    tick_array_upper: &AccountInfo<'info>,
+    token_mint: &AccountInfo<'info>,
+    token_recipient_account: &AccountInfo<'info>,
+    raydium_remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let token_is_mint_a = launch::token_is_mint_a(token_mint.key, accounts.wsol_mint.key);
+    check_raydium_addresses(
+        accounts,
+        token_is_mint_a,
+        personal_position,
+        pool_id,
+        protocol_position,
+        token_vault,
+        wsol_vault,
+        tick_array_lower,
+        tick_array_upper,
+        token_mint,
+        raydium_remaining_accounts,
+    )?;
+    let (token_vault_0, token_vault_1) = launch::ordered(token_is_mint_a, token_vault, wsol_vault);
+    let (recipient_token_account_0, recipient_token_account_1) =
+        launch::ordered(token_is_mint_a, token_recipient_account, &accounts.wsol_recipient_account);
+    let (vault_0_mint, vault_1_mint) = launch::ordered(token_is_mint_a, token_mint, &accounts.wsol_mint);
+    let seeds = &[b"position_nft_owner_pda".as_ref(), &[position_nft_owner_bump]];
+    let signer_seeds = &[&seeds[..]];
+    raydium::harvest(
+        CpiContext::new_with_signer(
+            accounts.clmm_program.clone(),
+            raydium::DecreaseLiquidityV2 {
+                position_nft_owner: accounts.position_nft_owner_pda.clone(),
+                position_nft_account: position_nft_account.clone(),
+                personal_position: personal_position.clone(),
+                pool_state: pool_id.clone(),
+                protocol_position: protocol_position.clone(),
+                token_vault_0: token_vault_0.clone(),
+                token_vault_1: token_vault_1.clone(),
+                tick_array_lower: tick_array_lower.clone(),
+                tick_array_upper: tick_array_upper.clone(),
+                recipient_token_account_0: recipient_token_account_0.clone(),
+                recipient_token_account_1: recipient_token_account_1.clone(),
+                token_program: accounts.token_program.clone(),
+                token_2022_program: accounts.token_2022_program.clone(),
+                memo_program: accounts.memo_program.clone(),
+                vault_0_mint: vault_0_mint.clone(),
+                vault_1_mint: vault_1_mint.clone(),
+            },
+            signer_seeds,
+        ).with_remaining_accounts(raydium_remaining_accounts.to_vec()),
+    )
+}
+
+// Checks Raydium accounts of a position against PDA derivations from its pool and personal position state.
+// Position NFT account is checked by Raydium against personal position's NFT mint.
+fn check_raydium_addresses(
+    accounts: &HarvestCommonAccounts<'_>,
+    token_is_mint_a: bool,
+    personal_position: &AccountInfo<'_>,
+    pool_id: &AccountInfo<'_>,
+    protocol_position: &AccountInfo<'_>,
+    token_vault: &AccountInfo<'_>,
+    wsol_vault: &AccountInfo<'_>,
+    tick_array_lower: &AccountInfo<'_>,
+    tick_array_upper: &AccountInfo<'_>,
+    token_mint: &AccountInfo<'_>,
+    raydium_remaining_accounts: &[AccountInfo<'_>],
+) -> Result<()> {
+    let clmm_program_id = accounts.clmm_program.key;
+    let pool_state = raydium::read_pool_state(pool_id, clmm_program_id)?;
+    let (mint_a, mint_b) = launch::ordered(token_is_mint_a, token_mint.key, accounts.wsol_mint.key);
+    require_keys_eq!(
+        raydium::pool_address(clmm_program_id, &pool_state.amm_config, mint_a, mint_b),
+        pool_id.key(),
+        CustomError::InvalidPool
+    );
+    require_keys_eq!(
+        raydium::pool_vault_address(clmm_program_id, pool_id.key, token_mint.key),
+        token_vault.key(),
+        CustomError::InvalidPoolVault
+    );
+    require_keys_eq!(
+        raydium::pool_vault_address(clmm_program_id, pool_id.key, accounts.wsol_mint.key),
+        wsol_vault.key(),
+        CustomError::InvalidPoolVault
+    );
+    let position = raydium::read_personal_position(personal_position, clmm_program_id)?;
+    require_keys_eq!(
+        raydium::personal_position_address(clmm_program_id, &position.nft_mint),
+        personal_position.key(),
+        CustomError::InvalidPersonalPosition
+    );
+    require_keys_eq!(position.pool_id, pool_id.key(), CustomError::InvalidPersonalPosition);
+    require_keys_eq!(
+        raydium::protocol_position_address(clmm_program_id, pool_id.key, position.tick_lower_index, position.tick_upper_index),
+        protocol_position.key(),
+        CustomError::InvalidProtocolPosition
+    );
+    let tick_array_lower_start_index = math::tick_array_start_index(position.tick_lower_index, pool_state.tick_spacing);
+    let tick_array_upper_start_index = math::tick_array_start_index(position.tick_upper_index, pool_state.tick_spacing);
+    require_keys_eq!(
+        raydium::tick_array_address(clmm_program_id, pool_id.key, tick_array_lower_start_index),
+        tick_array_lower.key(),
+        CustomError::InvalidTickArray
+    );
+    require_keys_eq!(
+        raydium::tick_array_address(clmm_program_id, pool_id.key, tick_array_upper_start_index),
+        tick_array_upper.key(),
+        CustomError::InvalidTickArray
+    );
+    // Optional exTickArrayBitmap is the first of Raydium remaining accounts, see raydium::harvest
+    if raydium_remaining_accounts.len() % 3 == 1 {
+        require_keys_eq!(
+            raydium::tick_array_bitmap_extension_address(clmm_program_id, pool_id.key),
+            raydium_remaining_accounts[0].key(),
+            CustomError::InvalidTickArrayBitmapExtension
+        );
+    }
+    Ok(())
+}
+
+// Harvests one position of harvest_many and pays token shares.
+// Creator's WSOL share is paid by the caller once the temporary WSOL account is closed.
+pub fn harvest_position_group<'info>(
+    accounts: &HarvestCommonAccounts<'info>,
+    position_nft_owner_bump: u8,
+    creator_fee_share_bps: u16,
+    group: &'info [AccountInfo<'info>],
+    raydium_remaining_accounts: &[AccountInfo<'info>],
+) -> Result<PositionHarvest<'info>> {
+    let [position_nft_account, personal_position, pool_id, protocol_position, token_vault, wsol_vault,
+        tick_array_lower, tick_array_upper, token_mint, token_recipient_account, fee_receiver_token_account,
+        launch_record_info, creator, creator_token_account, creator_escrow, creator_escrow_token_account] = group
+    else {
+        return err!(CustomError::InvalidPositionAccounts);
+    };
+
+    // Launch record identifies the position and its creator:
+    let mut launch_record = Account::<LaunchRecord>::try_from(launch_record_info)?;
+    let launch_record_address = Pubkey::create_program_address(
+        &[LAUNCH_RECORD_SEED, token_mint.key.as_ref(), &[launch_record.bump]],
+        &crate::ID,
+    ).map_err(|_| CustomError::LaunchRecordMismatch)?;
+    require_keys_eq!(launch_record_address, launch_record.key(), CustomError::LaunchRecordMismatch);
+    require_keys_eq!(launch_record.pool_id, pool_id.key(), CustomError::LaunchRecordMismatch);
+    require_keys_eq!(
+        get_associated_token_address(accounts.position_nft_owner_pda.key, &launch_record.position_nft_mint),
+        position_nft_account.key(),
+        CustomError::LaunchRecordMismatch
+    );
+    require_keys_eq!(launch_record.creator, creator.key(), CustomError::LaunchRecordMismatch);
+    require_keys_eq!(
+        get_associated_token_address_with_program_id(accounts.fee_receiver.key, token_mint.key, token_mint.owner),
+        fee_receiver_token_account.key(),
+        CustomError::InvalidPositionAccounts
+    );
+    require_keys_eq!(
+        get_associated_token_address_with_program_id(creator.key, token_mint.key, token_mint.owner),
+        creator_token_account.key(),
+        CustomError::LaunchRecordMismatch
+    );
+    let mint = InterfaceAccount::<Mint>::try_from(token_mint)?;
+    let token_mint_program = token_mint_program(accounts, token_mint);
+    let token_recipient = InterfaceAccount::<TokenAccount>::try_from(token_recipient_account)?;
+    require_keys_eq!(token_recipient.mint, token_mint.key(), CustomError::InvalidPositionAccounts);
+    require_keys_eq!(token_recipient.owner, accounts.payer.key(), CustomError::InvalidPositionAccounts);
+
+    // Harvest:
+    let token_balance_at_start = token_recipient.amount;
+    let wsol_balance_at_start = token::accessor::amount(&accounts.wsol_recipient_account)?;
+    harvest_position(
+        accounts,
+        position_nft_owner_bump,
+        position_nft_account,
+        personal_position,
+        pool_id,
+        protocol_position,
+        token_vault,
+        wsol_vault,
+        tick_array_lower,
+        tick_array_upper,
+        token_mint,
+        token_recipient_account,
+        raydium_remaining_accounts,
+    )?;
+    let token_harvested = amount_harvested(token_balance_at_start, token::accessor::amount(token_recipient_account)?)?;
+    let wsol_harvested = amount_harvested(wsol_balance_at_start, token::accessor::amount(&accounts.wsol_recipient_account)?)?;
+
+    // Record harvested amounts:
+    record_harvest(&mut launch_record, token_harvested, wsol_harvested)?;
+    launch_record.exit(&crate::ID)?;
+
+    // Pay token shares:
+    let creator_token_share = fee_share(token_harvested, creator_fee_share_bps);
+    let creator_wsol_share = fee_share(wsol_harvested, creator_fee_share_bps);
+    if creator_token_share > 0 {
+        let destination = if is_initialized_token_account(creator_token_account, token_mint.owner) {
+            creator_token_account
+        } else {
+            let creator_escrow = existing_creator_escrow(creator_escrow, creator, token_mint)?;
+            require_keys_eq!(
+                get_associated_token_address_with_program_id(&creator_escrow.key(), token_mint.key, token_mint.owner),
+                creator_escrow_token_account.key(),
+                CustomError::MissingCreatorAccounts
+            );
+            require!(
+                is_initialized_token_account(creator_escrow_token_account, token_mint.owner),
+                CustomError::MissingCreatorAccounts
+            );
+            creator_escrow_token_account
+        };
+        transfer_tokens(
+            token_mint_program,
+            token_recipient_account,
+            token_mint,
+            destination,
+            &accounts.payer,
+            creator_token_share,
+            mint.decimals,
+            &[],
+        )?;
+    }
+    transfer_tokens(
+        token_mint_program,
+        token_recipient_account,
+        token_mint,
+        fee_receiver_token_account,
+        &accounts.payer,
+        amount_after_share(token_harvested, creator_token_share)?,
+        mint.decimals,
+        &[],
+    )?;
+
+    // Destination of creator's WSOL share:
+    let creator_wsol_destination = if creator_wsol_share == 0 || creator.lamports() > 0 {
+        creator.clone()
+    } else {
+        existing_creator_escrow(creator_escrow, creator, token_mint)?;
+        creator_escrow.clone()
+    };
+    msg!("Harvested position {}: {} tokens, {} lamports", launch_record.position_nft_mint, token_harvested, wsol_harvested);
+    Ok(PositionHarvest {
+        event: FeesHarvested {
+            token_mint: token_mint.key(),
+            pool_id: pool_id.key(),
+            position_nft_mint: launch_record.position_nft_mint,
+            token_harvested,
+            wsol_harvested,
+            creator_token_share,
+            creator_wsol_share,
+        },
+        creator_wsol_destination,
+    })
+}
+
+// Token program owning the mint, the token may be a legacy or Token-2022 token
+pub fn token_mint_program<'a, 'info>(accounts: &'a HarvestCommonAccounts<'info>, token_mint: &AccountInfo<'info>) -> &'a AccountInfo<'info> {
+    if token_mint.owner == accounts.token_2022_program.key {
+        &accounts.token_2022_program
+    } else {
+        &accounts.token_program
+    }
+}
+
+// Token account exists if it is owned by token program and holds data
+pub fn is_initialized_token_account(account: &AccountInfo<'_>, token_program_id: &Pubkey) -> bool {
+    account.owner == token_program_id && !account.data_is_empty()
+}
+
+// Checks that creator's escrow for the token exists, it is not created in batch harvests
+fn existing_creator_escrow<'info>(
+    creator_escrow: &'info AccountInfo<'info>,
+    creator: &AccountInfo<'info>,
+    token_mint: &AccountInfo<'info>,
+) -> Result<Account<'info, CreatorEscrow>> {
+    let creator_escrow = Account::<CreatorEscrow>::try_from(creator_escrow)
+        .map_err(|_| CustomError::MissingCreatorAccounts)?;
+    require_keys_eq!(creator_escrow.creator, creator.key(), CustomError::LaunchRecordMismatch);
+    require_keys_eq!(creator_escrow.token_mint, token_mint.key(), CustomError::LaunchRecordMismatch);
+    Ok(creator_escrow)
+}
+
+// Creates creator's escrow for the token when creator's share is first paid into it, so that harvests
+// paying creator directly do not spend payer's lamports on escrow rent. Existing escrow is checked instead.
+pub fn init_creator_escrow_if_needed<'info>(
+    accounts: &HarvestCommonAccounts<'info>,
+    creator_escrow: &AccountInfo<'info>,
+    creator: &Pubkey,
+    token_mint: &Pubkey,
+    bump: u8,
+) -> Result<()> {
+    if !creator_escrow.data_is_empty() {
+        require_keys_eq!(*creator_escrow.owner, crate::ID, CustomError::LaunchRecordMismatch);
+        let escrow = CreatorEscrow::try_deserialize(&mut &creator_escrow.try_borrow_data()?[..])?;
+        require_keys_eq!(escrow.creator, *creator, CustomError::LaunchRecordMismatch);
+        require_keys_eq!(escrow.token_mint, *token_mint, CustomError::LaunchRecordMismatch);
+        return Ok(());
+    }
+    let signer_seeds: &[&[&[u8]]] = &[&[CREATOR_ESCROW_SEED, token_mint.as_ref(), &[bump]]];
+    create_pda_account(accounts, creator_escrow, 8 + CreatorEscrow::INIT_SPACE, signer_seeds)?;
+    let escrow = CreatorEscrow { creator: *creator, token_mint: *token_mint, bump };
+    escrow.try_serialize(&mut &mut creator_escrow.try_borrow_mut_data()?[..])
+}
+
+// Creates account at program's PDA funded by payer. Anyone can transfer lamports to the PDA beforehand,
+// which would make create_account fail, so such account is topped up, allocated and assigned instead.
+fn create_pda_account<'info>(
+    accounts: &HarvestCommonAccounts<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                CreateAccount {
+                    from: accounts.payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent_exempt_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+    transfer_lamports(
+        &accounts.system_program,
+        &accounts.payer,
+        account,
+        rent_exempt_lamports.saturating_sub(account.lamports()),
+    )?;
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
+// Transfers tokens authorized by the owner (signed by PDA seeds if any), skipping empty transfers.
+// Transfers are checked, as required by Token-2022 mints with extensions.
+pub fn transfer_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}
+
+// Transfers lamports from a system account, skipping empty transfers
+pub fn transfer_lamports<'info>(
+    system_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            system_program::Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_overflow<T>(result: Result<T>) -> bool {
+        matches!(result, Err(Error::AnchorError(error))
+            if error.error_code_number == anchor_lang::error::ERROR_CODE_OFFSET + CustomError::ArithmeticOverflow as u32)
+    }
+
+    #[test]
+    fn harvest_math_is_checked() {
+        assert_eq!(amount_harvested(5, 8).unwrap(), 3);
+        assert!(is_overflow(amount_harvested(8, 5)));  // Recipient balance decreased, e.g. by a transfer fee
+        assert_eq!(amount_after_share(10, 4).unwrap(), 6);
+        assert!(is_overflow(amount_after_share(4, 10)));
+
+        let mut launch_record = LaunchRecord {
+            creator: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            pool_id: Pubkey::default(),
+            position_nft_mint: Pubkey::default(),
+            token_is_mint_a: true,
+            tick_lower_index: 0,
+            tick_upper_index: 0,
+            initial_sqrt_price_x64: 0,
+            liquidity: 0,
+            launch_slot: 0,
+            launch_timestamp: 0,
+            token_harvested: 1,
+            wsol_harvested: u64::MAX - 1,
+            bump: 0,
+        };
+        record_harvest(&mut launch_record, 2, 1).unwrap();
+        assert_eq!((launch_record.token_harvested, launch_record.wsol_harvested), (3, u64::MAX));
+        assert!(is_overflow(record_harvest(&mut launch_record, 0, 1)));
+    }
+}
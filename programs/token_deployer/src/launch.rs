@@ -0,0 +1,757 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount, Transfer};
+use anchor_spl::associated_token::{self, get_associated_token_address, Create};
+use anchor_spl::metadata::{create_metadata_accounts_v3, CreateMetadataAccountsV3};
+use anchor_spl::token::{self, CloseAccount, InitializeAccount, MintTo, SetAuthority};
+use anchor_spl::token_2022::{self, spl_token_2022, InitializeMint2};
+use anchor_spl::token_interface;
+use anchor_spl::token_interface::{
+    metadata_pointer_initialize, token_metadata_initialize, token_metadata_update_authority, transfer_fee_initialize,
+    MetadataPointerInitialize, TokenMetadataInitialize, TokenMetadataUpdateAuthority, TransferFeeInitialize,
+};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::types::DataV2;
+use spl_token::instruction::AuthorityType;
+use spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+
+use crate::events::LaunchCompleted;
+use crate::math;
+use crate::raydium;
+use crate::registry;
+use crate::state::LaunchRecord;
+use crate::state::LauncherConfig;
+use crate::{CustomError, BPS_DENOMINATOR, MAX_TOKEN_DECIMALS, MIN_TOKEN_DECIMALS, TOKEN_ACCOUNT_SPACE};
+
+// Accounts used by create_token and create_and_launch to mint a new token
+pub struct MintTokenAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_metadata_account: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub metadata_program: AccountInfo<'info>,
+    pub rent_program: AccountInfo<'info>,
+}
+
+// Accounts used by create_token_2022 to mint a new Token-2022 token, mint and token account are created here
+pub struct MintToken2022Accounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub withheld_fee_authority: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+}
+
+// Accounts used by add_to_raydium and create_and_launch to create a pool and open a locked position
+pub struct LaunchAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub amm_config_id: AccountInfo<'info>,
+    pub pool_id: AccountInfo<'info>,
+    pub wsol_mint: AccountInfo<'info>,
+    pub token_vault: AccountInfo<'info>,
+    pub wsol_vault: AccountInfo<'info>,
+    pub observation_id: AccountInfo<'info>,
+    pub ex_tick_array_bitmap: AccountInfo<'info>,
+    pub position_nft_mint: AccountInfo<'info>,
+    pub position_nft_owner_pda: AccountInfo<'info>,
+    pub position_nft_account: AccountInfo<'info>,
+    pub metadata_account: AccountInfo<'info>,
+    pub protocol_position: AccountInfo<'info>,
+    pub tick_array_lower: AccountInfo<'info>,
+    pub tick_array_upper: AccountInfo<'info>,
+    pub personal_position: AccountInfo<'info>,
+    pub wsol_account: AccountInfo<'info>,
+    pub fee_receiver: AccountInfo<'info>,
+    pub clmm_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub token_mint_program: AccountInfo<'info>,  // Token program owning token_mint, either token_program or token_2022_program
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_2022_program: AccountInfo<'info>,
+    pub metadata_program: AccountInfo<'info>,
+    pub rent_program: AccountInfo<'info>,
+}
+
+// Mints supply to payer, adds immutable metadata and revokes mint authority
+pub fn mint_token(
+    accounts: MintTokenAccounts<'_>,
+    amount: u64,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    // Mint supply to payer:
+    token::mint_to(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            MintTo {
+                authority: accounts.payer.clone(),
+                mint: accounts.token_mint.clone(),
+                to: accounts.token_account.clone(),
+            },
+        ),
+        amount,
+    )?;
+    // Add metadata:
+    create_metadata_accounts_v3(
+        CpiContext::new(
+            accounts.metadata_program.clone(),
+            CreateMetadataAccountsV3 {
+                metadata: accounts.token_metadata_account.clone(),
+                mint: accounts.token_mint.clone(),
+                mint_authority: accounts.payer.clone(),
+                update_authority: accounts.payer.clone(),
+                payer: accounts.payer.clone(),
+                system_program: accounts.system_program.clone(),
+                rent: accounts.rent_program.clone(),
+            },
+        ),
+        DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        false,  // Is mutable
+        true,   // Update authority is signer
+        None,   // Collection details
+    )?;
+    // Revoke mint authority:
+    token::set_authority(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            SetAuthority {
+                current_authority: accounts.payer.clone(),
+                account_or_mint: accounts.token_mint.clone(),
+            },
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    Ok(())
+}
+
+// Creates Token-2022 mint with metadata pointer and token metadata extensions holding immutable metadata
+// on the mint itself, mints supply to payer's associated token account and revokes mint authority.
+// Non-zero transfer fee adds transfer fee extension which can not be changed later and whose withheld
+// fees can only be withdrawn by the program's withheld fee authority.
+pub fn mint_token_2022(
+    accounts: MintToken2022Accounts<'_>,
+    amount: u64,
+    name: String,
+    symbol: String,
+    uri: String,
+    decimals: u8,
+    transfer_fee_basis_points: u16,
+) -> Result<()> {
+    // Token metadata is appended to the mint by token_metadata_initialize, which requires the mint
+    // to already hold enough lamports for the resized account:
+    let mut extensions = vec![ExtensionType::MetadataPointer];
+    if transfer_fee_basis_points > 0 {
+        extensions.push(ExtensionType::TransferFeeConfig);
+    }
+    let mint_space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)?;
+    let metadata_space = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(accounts.payer.key()))?,
+        mint: accounts.token_mint.key(),
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    }.tlv_size_of()?;
+    system_program::create_account(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            CreateAccount {
+                from: accounts.payer.clone(),
+                to: accounts.token_mint.clone(),
+            },
+        ),
+        Rent::get()?.minimum_balance(mint_space + metadata_space),
+        mint_space as u64,
+        accounts.token_program.key,
+    )?;
+    // Metadata is stored on the mint itself:
+    metadata_pointer_initialize(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: accounts.token_program.clone(),
+                mint: accounts.token_mint.clone(),
+            },
+        ),
+        None,  // Authority
+        Some(accounts.token_mint.key()),
+    )?;
+    if transfer_fee_basis_points > 0 {
+        transfer_fee_initialize(
+            CpiContext::new(
+                accounts.token_program.clone(),
+                TransferFeeInitialize {
+                    token_program_id: accounts.token_program.clone(),
+                    mint: accounts.token_mint.clone(),
+                },
+            ),
+            None,  // Transfer fee config authority
+            Some(accounts.withheld_fee_authority.key),
+            transfer_fee_basis_points,
+            u64::MAX,  // Maximum fee
+        )?;
+    }
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            InitializeMint2 {
+                mint: accounts.token_mint.clone(),
+            },
+        ),
+        decimals,
+        accounts.payer.key,
+        None,  // Freeze authority
+    )?;
+    // Add metadata and make it immutable:
+    token_metadata_initialize(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            TokenMetadataInitialize {
+                token_program_id: accounts.token_program.clone(),
+                metadata: accounts.token_mint.clone(),
+                update_authority: accounts.payer.clone(),
+                mint_authority: accounts.payer.clone(),
+                mint: accounts.token_mint.clone(),
+            },
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+    token_metadata_update_authority(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            TokenMetadataUpdateAuthority {
+                token_program_id: accounts.token_program.clone(),
+                metadata: accounts.token_mint.clone(),
+                current_authority: accounts.payer.clone(),
+                new_authority: accounts.payer.clone(),  // Unused by the instruction
+            },
+        ),
+        OptionalNonZeroPubkey::default(),
+    )?;
+    // Mint supply to payer:
+    associated_token::create(
+        CpiContext::new(
+            accounts.associated_token_program.clone(),
+            Create {
+                payer: accounts.payer.clone(),
+                associated_token: accounts.token_account.clone(),
+                authority: accounts.payer.clone(),
+                mint: accounts.token_mint.clone(),
+                system_program: accounts.system_program.clone(),
+                token_program: accounts.token_program.clone(),
+            },
+        ),
+    )?;
+    token_2022::mint_to(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            token_2022::MintTo {
+                authority: accounts.payer.clone(),
+                mint: accounts.token_mint.clone(),
+                to: accounts.token_account.clone(),
+            },
+        ),
+        amount,
+    )?;
+    // Revoke mint authority:
+    token_2022::set_authority(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            token_2022::SetAuthority {
+                current_authority: accounts.payer.clone(),
+                account_or_mint: accounts.token_mint.clone(),
+            },
+        ),
+        spl_token_2022::instruction::AuthorityType::MintTokens,
+        None,
+    )?;
+    Ok(())
+}
+
+// Raydium CLMM requires mint_a < mint_b, so the token is side A only if it sorts before WSOL
+pub fn token_is_mint_a(token_mint: &Pubkey, wsol_mint: &Pubkey) -> bool {
+    token_mint < wsol_mint
+}
+
+// Converts price and tick range given as SOL per token to pool orientation (price of side A in side B).
+// When the token is side B both are inverted.
+pub fn to_pool_orientation(
+    token_is_mint_a: bool,
+    sqrt_price_x64: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Result<(u128, i32, i32)> {
+    if token_is_mint_a {
+        Ok((sqrt_price_x64, tick_lower_index, tick_upper_index))
+    } else {
+        let (tick_lower_index, tick_upper_index) = math::invert_tick_range(tick_lower_index, tick_upper_index)?;
+        Ok((math::invert_sqrt_price_x64(sqrt_price_x64)?, tick_lower_index, tick_upper_index))
+    }
+}
+
+// Creates TOKEN/WSOL pool, opens position owned by the program's PDA and charges the launch fee.
+// Price and tick range are in pool orientation (see to_pool_orientation); when the token is side B
+// mint, vault and owner account roles are swapped. Tick array accounts refer to the pool's range.
+// Returns amount of WSOL deposited into the position.
+pub fn launch_on_raydium(
+    accounts: &LaunchAccounts<'_>,
+    config: &LauncherConfig,
+    position: &math::PoolPosition,
+    token_amount_max: u64,
+    wsol_amount_max: u64,
+) -> Result<u64> {
+    let math::PoolPosition { sqrt_price_x64, tick_lower_index, tick_upper_index, liquidity } = *position;
+    let fee_amount = config.fee_amount;
+    let token_is_mint_a = token_is_mint_a(accounts.token_mint.key, accounts.wsol_mint.key);
+    let tick_spacing = raydium::read_tick_spacing(&accounts.amm_config_id, accounts.clmm_program.key)?;
+    math::check_tick_range(tick_lower_index, tick_upper_index, tick_spacing)?;
+    raydium::check_observation_account(&accounts.observation_id, accounts.clmm_program.key)?;
+    let tick_array_lower_start_index = math::tick_array_start_index(tick_lower_index, tick_spacing);
+    let tick_array_upper_start_index = math::tick_array_start_index(tick_upper_index, tick_spacing);
+    let (mint_a, mint_b) = ordered(token_is_mint_a, &accounts.token_mint, &accounts.wsol_mint);
+    check_raydium_addresses(
+        accounts,
+        mint_a.key,
+        mint_b.key,
+        tick_lower_index,
+        tick_upper_index,
+        tick_array_lower_start_index,
+        tick_array_upper_start_index,
+    )?;
+    let (mint_program_a, mint_program_b) = ordered(token_is_mint_a, &accounts.token_mint_program, &accounts.token_program);
+    let (vault_a, vault_b) = ordered(token_is_mint_a, &accounts.token_vault, &accounts.wsol_vault);
+    let (owner_account_a, owner_account_b) = ordered(token_is_mint_a, &accounts.token_account, &accounts.wsol_account);
+    let (amount_max_a, amount_max_b) = ordered(token_is_mint_a, token_amount_max, wsol_amount_max);
+
+    // Create, initialize and fund temporary WSOL account:
+    system_program::create_account(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            CreateAccount {
+                from: accounts.payer.clone(),
+                to: accounts.wsol_account.clone(),
+            },
+        ),
+        Rent::get()?
+            .minimum_balance(TOKEN_ACCOUNT_SPACE as usize)
+            .checked_add(wsol_amount_max)
+            .ok_or(CustomError::ArithmeticOverflow)?,
+        TOKEN_ACCOUNT_SPACE,
+        accounts.token_program.key,
+    )?;
+    token::initialize_account(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            InitializeAccount {
+                account: accounts.wsol_account.clone(),
+                mint: accounts.wsol_mint.clone(),
+                authority: accounts.payer.clone(),
+                rent: accounts.rent_program.clone(),
+            }
+        )
+    )?;
+    // Create pool:
+    raydium::create_pool(
+        CpiContext::new(
+            accounts.clmm_program.clone(),
+            raydium::CreatePool {
+                pool_creator: accounts.payer.clone(),
+                amm_config_id: accounts.amm_config_id.clone(),
+                pool_id: accounts.pool_id.clone(),
+                mint_a: mint_a.clone(),
+                mint_b: mint_b.clone(),
+                mint_vault_a: vault_a.clone(),
+                mint_vault_b: vault_b.clone(),
+                observation_id: accounts.observation_id.clone(),
+                ex_tick_array_bitmap: accounts.ex_tick_array_bitmap.clone(),
+                mint_program_id_a: mint_program_a.clone(),
+                mint_program_id_b: mint_program_b.clone(),
+                system_program: accounts.system_program.clone(),
+                rent_program: accounts.rent_program.clone(),
+            },
+        ),
+        raydium::CreatePoolArgs { sqrt_price_x64, open_time: 0 },
+    )?;
+    // Open position:
+    raydium::open_position(
+        CpiContext::new(
+            accounts.clmm_program.clone(),
+            raydium::OpenPosition {
+                payer: accounts.payer.clone(),
+                position_nft_owner: accounts.position_nft_owner_pda.clone(),
+                position_nft_mint: accounts.position_nft_mint.clone(),
+                position_nft_account: accounts.position_nft_account.clone(),
+                metadata_account: accounts.metadata_account.clone(),
+                pool_id: accounts.pool_id.clone(),
+                protocol_position: accounts.protocol_position.clone(),
+                tick_array_lower: accounts.tick_array_lower.clone(),
+                tick_array_upper: accounts.tick_array_upper.clone(),
+                personal_position: accounts.personal_position.clone(),
+                owner_token_account_a: owner_account_a.clone(),
+                owner_token_account_b: owner_account_b.clone(),
+                token_vault_a: vault_a.clone(),
+                token_vault_b: vault_b.clone(),
+                rent_program: accounts.rent_program.clone(),
+                system_program: accounts.system_program.clone(),
+                token_program: accounts.token_program.clone(),
+                associated_token_program: accounts.associated_token_program.clone(),
+                metadata_program: accounts.metadata_program.clone(),
+                token_2022_program: accounts.token_2022_program.clone(),
+                token_mint_a: mint_a.clone(),
+                token_mint_b: mint_b.clone(),
+            },
+        ),
+        raydium::OpenPositionV2Args {
+            tick_lower_index,
+            tick_upper_index,
+            tick_array_lower_start_index,
+            tick_array_upper_start_index,
+            liquidity,
+            amount_0_max: amount_max_a,
+            amount_1_max: amount_max_b,
+            with_metadata: true,
+            base_flag: None,
+        },
+    )?;
+    check_position_opened(accounts, liquidity)?;
+    let wsol_deposited = wsol_amount_max
+        .checked_sub(token::accessor::amount(&accounts.wsol_account)?)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    // Close temporary SOL account:
+    token::close_account(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            CloseAccount {
+                account: accounts.wsol_account.clone(),
+                destination: accounts.payer.clone(),
+                authority: accounts.payer.clone(),
+            },
+        )
+    )?;
+    // Fee:
+    system_program::transfer(
+        CpiContext::new(
+            accounts.system_program.clone(),
+            Transfer {
+                from: accounts.payer.clone(),
+                to: accounts.fee_receiver.clone(),
+            },
+        ),
+        fee_amount
+    )?;
+    Ok(wsol_deposited)
+}
+
+// Completes launch of a position opened by launch_on_raydium (in pool orientation): records it in `launch_record`,
+// requires that the configured share of `supply` was provided into liquidity and returns LaunchCompleted event
+// for the instruction to emit. `token_balance_at_start` is payer's token balance before the launch.
+pub fn complete_launch(
+    accounts: &LaunchAccounts<'_>,
+    config: &LauncherConfig,
+    launch_record: &mut Account<'_, LaunchRecord>,
+    launch_record_bump: u8,
+    position: &math::PoolPosition,
+    supply: u64,
+    token_balance_at_start: u64,
+    wsol_deposited: u64,
+) -> Result<LaunchCompleted> {
+    launch_record.set_inner(new_launch_record(accounts, launch_record_bump, position)?);
+    let token_balance_at_end = token::accessor::amount(&accounts.token_account)?;
+    let token_amount_spent = token_amount_spent(token_balance_at_start, token_balance_at_end)?;
+    check_liquidity_floor(token_amount_spent, supply, config.min_liquidity_bps_of_supply)?;
+    Ok(launch_completed(launch_record, token_amount_spent, wsol_deposited, config.fee_amount))
+}
+
+// Builds LaunchRecord of a position opened by launch_on_raydium, price and tick range are in pool orientation
+fn new_launch_record(accounts: &LaunchAccounts<'_>, bump: u8, position: &math::PoolPosition) -> Result<LaunchRecord> {
+    let clock = Clock::get()?;
+    Ok(LaunchRecord {
+        creator: accounts.payer.key(),
+        token_mint: accounts.token_mint.key(),
+        pool_id: accounts.pool_id.key(),
+        position_nft_mint: accounts.position_nft_mint.key(),
+        token_is_mint_a: token_is_mint_a(accounts.token_mint.key, accounts.wsol_mint.key),
+        tick_lower_index: position.tick_lower_index,
+        tick_upper_index: position.tick_upper_index,
+        initial_sqrt_price_x64: position.sqrt_price_x64,
+        liquidity: position.liquidity,
+        launch_slot: clock.slot,
+        launch_timestamp: clock.unix_timestamp,
+        token_harvested: 0,
+        wsol_harvested: 0,
+        bump,
+    })
+}
+
+// Builds LaunchCompleted event of a recorded launch
+fn launch_completed(
+    launch_record: &LaunchRecord,
+    token_deposited: u64,
+    wsol_deposited: u64,
+    fee_paid: u64,
+) -> LaunchCompleted {
+    LaunchCompleted {
+        token_mint: launch_record.token_mint,
+        creator: launch_record.creator,
+        pool_id: launch_record.pool_id,
+        position_nft_mint: launch_record.position_nft_mint,
+        token_is_mint_a: launch_record.token_is_mint_a,
+        tick_lower_index: launch_record.tick_lower_index,
+        tick_upper_index: launch_record.tick_upper_index,
+        sqrt_price_x64: launch_record.initial_sqrt_price_x64,
+        liquidity: launch_record.liquidity,
+        token_deposited,
+        wsol_deposited,
+        fee_paid,
+    }
+}
+
+// Checks Raydium accounts of pool and position to be created against their PDA derivations.
+// Observation account is not a PDA, it is checked by check_observation_account.
+fn check_raydium_addresses(
+    accounts: &LaunchAccounts<'_>,
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    tick_array_lower_start_index: i32,
+    tick_array_upper_start_index: i32,
+) -> Result<()> {
+    let clmm_program_id = accounts.clmm_program.key;
+    let pool = raydium::pool_address(clmm_program_id, accounts.amm_config_id.key, mint_a, mint_b);
+    require_keys_eq!(pool, accounts.pool_id.key(), CustomError::InvalidPool);
+    require_keys_eq!(
+        raydium::pool_vault_address(clmm_program_id, &pool, accounts.token_mint.key),
+        accounts.token_vault.key(),
+        CustomError::InvalidPoolVault
+    );
+    require_keys_eq!(
+        raydium::pool_vault_address(clmm_program_id, &pool, accounts.wsol_mint.key),
+        accounts.wsol_vault.key(),
+        CustomError::InvalidPoolVault
+    );
+    require_keys_eq!(
+        raydium::tick_array_bitmap_extension_address(clmm_program_id, &pool),
+        accounts.ex_tick_array_bitmap.key(),
+        CustomError::InvalidTickArrayBitmapExtension
+    );
+    require_keys_eq!(
+        raydium::protocol_position_address(clmm_program_id, &pool, tick_lower_index, tick_upper_index),
+        accounts.protocol_position.key(),
+        CustomError::InvalidProtocolPosition
+    );
+    require_keys_eq!(
+        raydium::personal_position_address(clmm_program_id, accounts.position_nft_mint.key),
+        accounts.personal_position.key(),
+        CustomError::InvalidPersonalPosition
+    );
+    require_keys_eq!(
+        raydium::tick_array_address(clmm_program_id, &pool, tick_array_lower_start_index),
+        accounts.tick_array_lower.key(),
+        CustomError::InvalidTickArray
+    );
+    require_keys_eq!(
+        raydium::tick_array_address(clmm_program_id, &pool, tick_array_upper_start_index),
+        accounts.tick_array_upper.key(),
+        CustomError::InvalidTickArray
+    );
+    Ok(())
+}
+
+// Checks that position opened by Raydium holds requested liquidity and its NFT was minted to
+// position_nft_owner_pda's associated token account, so liquidity can never be withdrawn
+fn check_position_opened(accounts: &LaunchAccounts<'_>, liquidity: u128) -> Result<()> {
+    // open_position_v2 mints position NFT with the legacy token program
+    require_keys_eq!(
+        get_associated_token_address(accounts.position_nft_owner_pda.key, accounts.position_nft_mint.key),
+        accounts.position_nft_account.key(),
+        CustomError::PositionNftNotHeldByProgram
+    );
+    require_keys_eq!(*accounts.position_nft_account.owner, token::ID, CustomError::PositionNftNotHeldByProgram);
+    require_keys_eq!(
+        token::accessor::authority(&accounts.position_nft_account)?,
+        accounts.position_nft_owner_pda.key(),
+        CustomError::PositionNftNotHeldByProgram
+    );
+    require_eq!(token::accessor::amount(&accounts.position_nft_account)?, 1, CustomError::PositionNftNotHeldByProgram);
+    let position = raydium::read_personal_position(&accounts.personal_position, accounts.clmm_program.key)?;
+    require_eq!(position.liquidity, liquidity, CustomError::PositionLiquidityMismatch);
+    Ok(())
+}
+
+// Returns (token side, WSOL side) as (side A, side B) of the pool
+pub fn ordered<T>(token_is_mint_a: bool, token_side: T, wsol_side: T) -> (T, T) {
+    if token_is_mint_a {
+        (token_side, wsol_side)
+    } else {
+        (wsol_side, token_side)
+    }
+}
+
+// Checks decimals and total supply of a token to be created against allowed range and config bounds
+pub fn check_token_supply(amount: u64, decimals: u8, config: &LauncherConfig) -> Result<()> {
+    require!((MIN_TOKEN_DECIMALS..=MAX_TOKEN_DECIMALS).contains(&decimals), CustomError::InvalidDecimals);
+    // Bounds are in whole tokens, u64 bounds times 10^9 fit into u128:
+    let one_token = 10u128.pow(decimals as u32);
+    require!(amount as u128 >= config.min_supply as u128 * one_token, CustomError::SupplyTooLow);
+    require!(amount as u128 <= config.max_supply as u128 * one_token, CustomError::SupplyTooHigh);
+    Ok(())
+}
+
+// Checks that a token launched outside create_and_launch cannot be changed after launch:
+// no mint or freeze authority, immutable metadata, and the whole supply held by the payer.
+pub fn check_mint_invariants(
+    token_mint: &InterfaceAccount<'_, token_interface::Mint>,
+    token_metadata_account: &AccountInfo<'_>,
+    symbol_registry: &AccountInfo<'_>,
+    payer_token_balance: u64,
+) -> Result<()> {
+    require!(token_mint.mint_authority.is_none(), CustomError::MintAuthorityNotRevoked);
+    require!(token_mint.freeze_authority.is_none(), CustomError::FreezeAuthorityNotRevoked);
+    let symbol = immutable_metadata_symbol(&token_mint.to_account_info(), token_metadata_account)?;
+    registry::check_symbol_registered(symbol_registry, &symbol, &token_mint.key())?;
+    require_eq!(token_mint.supply, payer_token_balance, CustomError::SupplyNotHeldByPayer);
+    Ok(())
+}
+
+// Returns symbol of immutable token metadata. Metadata is either stored on a Token-2022 mint itself
+// (`token_metadata_account` is the mint, as created by create_token_2022) or in Metaplex metadata account of the mint
+fn immutable_metadata_symbol(token_mint: &AccountInfo<'_>, token_metadata_account: &AccountInfo<'_>) -> Result<String> {
+    if token_metadata_account.key == token_mint.key {
+        require_keys_eq!(*token_mint.owner, token_2022::ID, CustomError::MissingTokenMetadata);
+        let data = token_mint.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        let metadata_address = mint.get_extension::<MetadataPointer>()
+            .map_err(|_| CustomError::MissingTokenMetadata)?
+            .metadata_address;
+        require!(Option::<Pubkey>::from(metadata_address) == Some(token_mint.key()), CustomError::MissingTokenMetadata);
+        let metadata = mint.get_variable_len_extension::<TokenMetadata>().map_err(|_| CustomError::MissingTokenMetadata)?;
+        require!(Option::<Pubkey>::from(metadata.update_authority).is_none(), CustomError::MutableTokenMetadata);
+        Ok(metadata.symbol)
+    } else {
+        require_keys_eq!(token_metadata_account.key(), Metadata::find_pda(token_mint.key).0, CustomError::MissingTokenMetadata);
+        require_keys_eq!(*token_metadata_account.owner, mpl_token_metadata::ID, CustomError::MissingTokenMetadata);
+        let metadata = Metadata::safe_deserialize(&token_metadata_account.try_borrow_data()?)
+            .map_err(|_| CustomError::MissingTokenMetadata)?;
+        require!(!metadata.is_mutable, CustomError::MutableTokenMetadata);
+        // Metaplex pads symbol with null characters
+        Ok(metadata.symbol.trim_end_matches('\0').to_string())
+    }
+}
+
+// Requires that at least `min_liquidity_bps_of_supply` of total supply was provided into liquidity
+pub fn check_liquidity_floor(token_amount_spent: u64, supply: u64, min_liquidity_bps_of_supply: u64) -> Result<()> {
+    require!(token_amount_spent as u128 >= liquidity_floor(supply, min_liquidity_bps_of_supply)?, CustomError::InsufficientLiquidityProvided);
+    Ok(())
+}
+
+// Minimum amount of tokens to provide into liquidity: `supply * bps / 10_000` computed in u128 and rounded up,
+// so small supplies (e.g. tokens with few decimals) are not rounded down below the configured share
+pub fn liquidity_floor(supply: u64, min_liquidity_bps_of_supply: u64) -> Result<u128> {
+    let product = (supply as u128)
+        .checked_mul(min_liquidity_bps_of_supply as u128)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    Ok(product.div_ceil(BPS_DENOMINATOR as u128))
+}
+
+// Tokens moved out of the payer's token account during launch
+pub fn token_amount_spent(balance_at_start: u64, balance_at_end: u64) -> Result<u64> {
+    let token_amount_spent = balance_at_start.checked_sub(balance_at_end).ok_or(CustomError::ArithmeticOverflow)?;
+    Ok(token_amount_spent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn error_code(result: Result<impl std::fmt::Debug>) -> u32 {
+        match result.unwrap_err() {
+            Error::AnchorError(error) => error.error_code_number,
+            error => panic!("unexpected error {error:?}"),
+        }
+    }
+
+    fn custom_error_code(error: CustomError) -> u32 {
+        anchor_lang::error::ERROR_CODE_OFFSET + error as u32
+    }
+
+    // Supplies around powers of ten, multiples of 10_000 and u64 bounds
+    fn boundary_supply() -> impl Strategy<Value = u64> {
+        prop_oneof![
+            0..=20_000u64,
+            (0..=19u32, -2..=2i64).prop_map(|(exp, delta)| 10u64.pow(exp).saturating_add_signed(delta)),
+            (0..=u64::MAX / 10_000, 0..=1u64).prop_map(|(n, delta)| (n * 10_000).saturating_sub(delta)),
+            (u64::MAX - 20_000..=u64::MAX),
+            any::<u64>(),
+        ]
+    }
+
+    #[test]
+    fn liquidity_floor_bounds() {
+        assert_eq!(liquidity_floor(u64::MAX, BPS_DENOMINATOR).unwrap(), u64::MAX as u128);
+        assert_eq!(liquidity_floor(u64::MAX, u64::MAX).unwrap(), (u64::MAX as u128 * u64::MAX as u128).div_ceil(10_000));
+        assert_eq!(liquidity_floor(1, 1).unwrap(), 1);
+        assert_eq!(liquidity_floor(19, 9_500).unwrap(), 19);  // 18.05 rounded up
+        assert_eq!(liquidity_floor(20, 9_500).unwrap(), 19);
+        assert_eq!(liquidity_floor(1_000_000_000, 0).unwrap(), 0);
+        assert_eq!(error_code(token_amount_spent(1, 2)), custom_error_code(CustomError::ArithmeticOverflow));
+    }
+
+    proptest! {
+        #[test]
+        fn liquidity_floor_is_exact_ceiling(supply in boundary_supply(), bps in 0..=BPS_DENOMINATOR) {
+            let floor = liquidity_floor(supply, bps).unwrap();
+            let required = supply as u128 * bps as u128;
+            prop_assert!(floor * 10_000 >= required);
+            prop_assert!(floor == 0 || (floor - 1) * 10_000 < required);
+            prop_assert!(floor <= supply as u128);
+        }
+
+        #[test]
+        fn liquidity_floor_check_matches_exact_share(supply in boundary_supply(), bps in 0..=BPS_DENOMINATOR, spent_fraction in 0.0..=1.0f64) {
+            let floor = liquidity_floor(supply, bps).unwrap() as u64;
+            for spent in [(supply as f64 * spent_fraction) as u64, floor, floor.saturating_sub(1), supply] {
+                let provides_share = spent as u128 * 10_000 >= supply as u128 * bps as u128;
+                match check_liquidity_floor(spent, supply, bps) {
+                    Ok(()) => prop_assert!(provides_share),
+                    result => {
+                        prop_assert!(!provides_share);
+                        prop_assert_eq!(error_code(result), custom_error_code(CustomError::InsufficientLiquidityProvided));
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn token_amount_spent_is_checked(start in any::<u64>(), end in any::<u64>()) {
+            match token_amount_spent(start, end) {
+                Ok(spent) => prop_assert_eq!(spent, start - end),
+                result => {
+                    prop_assert!(end > start);
+                    prop_assert_eq!(error_code(result), custom_error_code(CustomError::ArithmeticOverflow));
+                }
+            }
+        }
+    }
+}
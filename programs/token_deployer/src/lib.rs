@@ -1,26 +1,71 @@
-mod raydium;
+#![allow(clippy::too_many_arguments)]
+
+mod events;
+mod harvest;
+mod launch;
+pub mod math;
+pub mod metadata;
+pub mod raydium;
+mod registry;
+pub mod state;
 
 use anchor_lang::prelude::*;
-use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::metadata::{create_metadata_accounts_v3, CreateMetadataAccountsV3, Metadata};
-use anchor_spl::token::{self, CloseAccount, InitializeAccount, Mint, MintTo, SetAuthority, Token, TokenAccount};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken};
+use anchor_spl::metadata::Metadata;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use anchor_spl::token_2022::Token2022;
-use anchor_lang::system_program::{self, CreateAccount, Transfer};
-use mpl_token_metadata::types::DataV2;
-use raydium::*;
+use anchor_spl::token_interface::{self, TokenInterface};
+use events::*;
+use harvest::fee_share;
+use launch::*;
+// Pool orientation of a token, shared with off-chain clients
+pub use launch::token_is_mint_a;
+use metadata::TokenMetadataArgs;
+use registry::RegisterSymbolAccounts;
+use state::*;
 use solana_program::{pubkey, pubkey::Pubkey};
-use spl_token::instruction::AuthorityType;
 
-// CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK (mainnet)
-// devi51mZmdwUJGU9hjN27vEz64Gps7uUefqxg27EAtH  (devnet)
+// Exactly one cluster feature selects the cluster-specific addresses below:
+#[cfg(not(any(feature = "mainnet", feature = "devnet", feature = "localnet")))]
+compile_error!("One of `mainnet`, `devnet` or `localnet` features must be enabled");
+#[cfg(any(
+    all(feature = "mainnet", feature = "devnet"),
+    all(feature = "mainnet", feature = "localnet"),
+    all(feature = "devnet", feature = "localnet"),
+))]
+compile_error!("Only one of `mainnet`, `devnet` or `localnet` features can be enabled");
+
+#[cfg(feature = "mainnet")]
+pub const CLMM_PROGRAM_ID: Pubkey = pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK");
+#[cfg(feature = "mainnet")]
+pub const HARVESTER: Pubkey = pubkey!("8iv4cFhk7s7SCysedKgBC2haqEfvx8HmJ75PP5pmjn1b");
+
+#[cfg(feature = "devnet")]
+pub const CLMM_PROGRAM_ID: Pubkey = pubkey!("devi51mZmdwUJGU9hjN27vEz64Gps7uUefqxg27EAtH");
+#[cfg(feature = "devnet")]
+pub const HARVESTER: Pubkey = pubkey!("5TLHYfEXHmNCctnCurLiPzLkwW46gxECePQ9BTwGYfvD");
+
+// Local validator loads Raydium CLMM at its devnet address
+#[cfg(feature = "localnet")]
 pub const CLMM_PROGRAM_ID: Pubkey = pubkey!("devi51mZmdwUJGU9hjN27vEz64Gps7uUefqxg27EAtH");
-// 8iv4cFhk7s7SCysedKgBC2haqEfvx8HmJ75PP5pmjn1b (mainnet)
-// 5TLHYfEXHmNCctnCurLiPzLkwW46gxECePQ9BTwGYfvD (devnet)
+#[cfg(feature = "localnet")]
 pub const HARVESTER: Pubkey = pubkey!("5TLHYfEXHmNCctnCurLiPzLkwW46gxECePQ9BTwGYfvD");
 
 const TOKEN_ACCOUNT_SPACE: u64 = 165;
-const MIN_LIQUIDITY_PCT_OF_SUPPLY: u64 = 95;  // At least 95% of total supply must be provided to liquidity pool
-const FEE_AMOUNT: u64 = 400_000_000;  // 0.4 SOL
+// Allowed decimals of created tokens:
+const MIN_TOKEN_DECIMALS: u8 = 0;
+const MAX_TOKEN_DECIMALS: u8 = 9;
+// Defaults written by initialize_config, adjustable later with update_config:
+const DEFAULT_MIN_LIQUIDITY_BPS_OF_SUPPLY: u64 = 9_500;  // At least 95% of total supply must be provided to liquidity pool
+const DEFAULT_FEE_AMOUNT: u64 = 400_000_000;  // 0.4 SOL
+const DEFAULT_CREATOR_FEE_SHARE_BPS: u16 = 0;
+const DEFAULT_KEEPER_TIP_LAMPORTS: u64 = 1_000_000;  // 0.001 SOL
+const DEFAULT_CREATOR_WITHHELD_FEE_SHARE_BPS: u16 = 5_000;
+const MAX_TRANSFER_FEE_BPS: u16 = 500;  // 5%
+const DEFAULT_MIN_SUPPLY: u64 = 1;  // Whole tokens
+const DEFAULT_MAX_SUPPLY: u64 = 1_000_000_000_000;  // Whole tokens
+const BPS_DENOMINATOR: u64 = 10_000;
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
 
 declare_id!("RoCKeTyq1Wf8Co6e2x1RQCC6uxRkZrQ3mDJUEoQryhh");
 
@@ -32,262 +77,549 @@ mod token_deployer {
         Ok(())
     }
 
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.fee_amount = DEFAULT_FEE_AMOUNT;
+        config.fee_receiver = HARVESTER;
+        config.clmm_program_id = CLMM_PROGRAM_ID;
+        config.min_liquidity_bps_of_supply = DEFAULT_MIN_LIQUIDITY_BPS_OF_SUPPLY;
+        config.bump = ctx.bumps.config;
+        config.creator_fee_share_bps = DEFAULT_CREATOR_FEE_SHARE_BPS;
+        config.keeper_tip_lamports = DEFAULT_KEEPER_TIP_LAMPORTS;
+        config.creator_withheld_fee_share_bps = DEFAULT_CREATOR_WITHHELD_FEE_SHARE_BPS;
+        config.min_supply = DEFAULT_MIN_SUPPLY;
+        config.max_supply = DEFAULT_MAX_SUPPLY;
+        Ok(())
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        if let Some(admin) = args.admin {
+            config.admin = admin;
+        }
+        if let Some(fee_amount) = args.fee_amount {
+            config.fee_amount = fee_amount;
+        }
+        if let Some(fee_receiver) = args.fee_receiver {
+            config.fee_receiver = fee_receiver;
+        }
+        if let Some(clmm_program_id) = args.clmm_program_id {
+            config.clmm_program_id = clmm_program_id;
+        }
+        if let Some(min_liquidity_bps_of_supply) = args.min_liquidity_bps_of_supply {
+            require!(min_liquidity_bps_of_supply <= BPS_DENOMINATOR, CustomError::InvalidConfig);
+            config.min_liquidity_bps_of_supply = min_liquidity_bps_of_supply;
+        }
+        if let Some(creator_fee_share_bps) = args.creator_fee_share_bps {
+            require!(creator_fee_share_bps as u64 <= BPS_DENOMINATOR, CustomError::InvalidConfig);
+            config.creator_fee_share_bps = creator_fee_share_bps;
+        }
+        if let Some(keeper_tip_lamports) = args.keeper_tip_lamports {
+            config.keeper_tip_lamports = keeper_tip_lamports;
+        }
+        if let Some(creator_withheld_fee_share_bps) = args.creator_withheld_fee_share_bps {
+            require!(creator_withheld_fee_share_bps as u64 <= BPS_DENOMINATOR, CustomError::InvalidConfig);
+            config.creator_withheld_fee_share_bps = creator_withheld_fee_share_bps;
+        }
+        if let Some(min_supply) = args.min_supply {
+            config.min_supply = min_supply;
+        }
+        if let Some(max_supply) = args.max_supply {
+            config.max_supply = max_supply;
+        }
+        // Zero supply is never allowed:
+        require!(0 < config.min_supply && config.min_supply <= config.max_supply, CustomError::InvalidConfig);
+        Ok(())
+    }
+
+    // Reserves normalized symbol so that no token can be created with it
+    pub fn reserve_symbol(ctx: Context<ReserveSymbol>, symbol: String) -> Result<()> {
+        ctx.accounts.symbol_registry.set_inner(SymbolRegistry {
+            symbol: metadata::normalize_symbol(&symbol)?,
+            token_mint: Pubkey::default(),
+            creator: ctx.accounts.admin.key(),
+            reserved: true,
+            bump: ctx.bumps.symbol_registry,
+        });
+        Ok(())
+    }
+
+    // Releases reserved symbol, symbols registered by tokens can not be released
+    pub fn release_symbol(_ctx: Context<ReleaseSymbol>, _symbol: String) -> Result<()> {
+        Ok(())
+    }
+
     pub fn create_token(
         ctx: Context<CreateTokenAccounts>,
         amount: u64,
+        decimals: u8,
         name: String,
         symbol: String,
         uri: String
     ) -> Result<()> {
-        // Mint supply to payer:
-        token::mint_to(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    authority: ctx.accounts.payer.to_account_info(),
-                    mint: ctx.accounts.token_mint.to_account_info(),
-                    to: ctx.accounts.token_account.to_account_info(),
-                },
-            ),
+        launch::check_token_supply(amount, decimals, &ctx.accounts.config)?;
+        let TokenMetadataArgs { name, symbol, uri } = metadata::normalize_metadata(&name, &symbol, &uri)?;
+        registry::register_symbol(
+            RegisterSymbolAccounts {
+                payer: ctx.accounts.payer.to_account_info(),
+                token_mint: ctx.accounts.token_mint.to_account_info(),
+                symbol_registry: ctx.accounts.symbol_registry.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &symbol,
+        )?;
+        launch::mint_token(
+            MintTokenAccounts {
+                payer: ctx.accounts.payer.to_account_info(),
+                token_mint: ctx.accounts.token_mint.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                token_metadata_account: ctx.accounts.token_metadata_account.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                metadata_program: ctx.accounts.metadata_program.to_account_info(),
+                rent_program: ctx.accounts.rent_program.to_account_info(),
+            },
             amount,
+            name.clone(),
+            symbol.clone(),
+            uri.clone(),
         )?;
-        // Add metadata:
-        create_metadata_accounts_v3(
-			CpiContext::new(
-                ctx.accounts.metadata_program.to_account_info(),
-                CreateMetadataAccountsV3 {
-                    metadata: ctx.accounts.token_metadata_account.to_account_info(),
-                    mint: ctx.accounts.token_mint.to_account_info(),
-                    mint_authority: ctx.accounts.payer.to_account_info(),
-                    update_authority: ctx.accounts.payer.to_account_info(),
-                    payer: ctx.accounts.payer.to_account_info(),
-                    system_program: ctx.accounts.system_program.to_account_info(),
-                    rent: ctx.accounts.rent_program.to_account_info(),
-                },
-			),
-			DataV2 {
-                name: name,
-                symbol: symbol,
-                uri: uri,
-                seller_fee_basis_points: 0,
-                creators: None,
-                collection: None,
-                uses: None,
-			},
-			false,  // Is mutable
-			true,   // Update authority is signer
-			None,   // Collection details
-	    )?;
-        // Revoke mint authority:
-        token::set_authority(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                SetAuthority {
-                    current_authority: ctx.accounts.payer.to_account_info(),
-                    account_or_mint: ctx.accounts.token_mint.to_account_info(),
-                },
-            ),
-            AuthorityType::MintTokens,
-            None,
+        emit_cpi!(TokenCreated {
+            token_mint: ctx.accounts.token_mint.key(),
+            creator: ctx.accounts.payer.key(),
+            name,
+            symbol,
+            uri,
+            supply: amount,
+            decimals: ctx.accounts.token_mint.decimals,
+        });
+        Ok(())
+    }
+
+    pub fn create_token_2022(
+        ctx: Context<CreateToken2022Accounts>,
+        amount: u64,
+        decimals: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+        transfer_fee_basis_points: u16,
+    ) -> Result<()> {
+        launch::check_token_supply(amount, decimals, &ctx.accounts.config)?;
+        let TokenMetadataArgs { name, symbol, uri } = metadata::normalize_metadata(&name, &symbol, &uri)?;
+        registry::register_symbol(
+            RegisterSymbolAccounts {
+                payer: ctx.accounts.payer.to_account_info(),
+                token_mint: ctx.accounts.token_mint.to_account_info(),
+                symbol_registry: ctx.accounts.symbol_registry.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &symbol,
+        )?;
+        require!(transfer_fee_basis_points <= MAX_TRANSFER_FEE_BPS, CustomError::TransferFeeTooHigh);
+        launch::mint_token_2022(
+            MintToken2022Accounts {
+                payer: ctx.accounts.payer.to_account_info(),
+                token_mint: ctx.accounts.token_mint.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                withheld_fee_authority: ctx.accounts.withheld_fee_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            },
+            amount,
+            name.clone(),
+            symbol.clone(),
+            uri.clone(),
+            decimals,
+            transfer_fee_basis_points,
         )?;
+        emit_cpi!(TokenCreated {
+            token_mint: ctx.accounts.token_mint.key(),
+            creator: ctx.accounts.payer.key(),
+            name,
+            symbol,
+            uri,
+            supply: amount,
+            decimals,
+        });
         Ok(())
     }
 
+    // Caller must create `observation_id` earlier in the same transaction: it is larger than
+    // accounts allocated in CPI may be, so the program only checks it (see check_observation_account).
     pub fn add_to_raydium(
         ctx: Context<AddToRaydiumAccounts>,
         sqrt_price_x64: u128,
         tick_lower_index: i32,
         tick_upper_index: i32,
-        tick_array_lower_start_index: i32,
-        tick_array_upper_start_index: i32,
         liquidity: u128,
         token_amount_max: u64,
         wsol_amount_max: u64,
     ) -> Result<()> {
         let token_balance_at_start = ctx.accounts.token_account.amount;
-        // Create, initialize and fund temporary WSOL account:
-        anchor_lang::system_program::create_account(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                CreateAccount {
-                    from: ctx.accounts.payer.to_account_info(),
-                    to: ctx.accounts.wsol_account.to_account_info(),
-                },
-            ),
-            ctx.accounts.rent_program.minimum_balance(TOKEN_ACCOUNT_SPACE as usize) + wsol_amount_max,
-            TOKEN_ACCOUNT_SPACE,
-            &ctx.accounts.token_program.key(),
+        launch::check_mint_invariants(
+            &ctx.accounts.token_mint,
+            &ctx.accounts.token_metadata_account,
+            &ctx.accounts.symbol_registry,
+            token_balance_at_start,
         )?;
-        token::initialize_account(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                InitializeAccount {
-                    account: ctx.accounts.wsol_account.to_account_info(),
-                    mint: ctx.accounts.wsol_mint.to_account_info(),
-                    authority: ctx.accounts.payer.to_account_info(),
-                    rent: ctx.accounts.rent_program.to_account_info(),
-                }
-            )
+        let token_is_mint_a = launch::token_is_mint_a(&ctx.accounts.token_mint.key(), ctx.accounts.raydium.wsol_mint.key);
+        let (sqrt_price_x64, tick_lower_index, tick_upper_index) =
+            launch::to_pool_orientation(token_is_mint_a, sqrt_price_x64, tick_lower_index, tick_upper_index)?;
+        let position = math::PoolPosition { sqrt_price_x64, tick_lower_index, tick_upper_index, liquidity };
+        let launch_accounts = ctx.accounts.launch_accounts()?;
+        let wsol_deposited = launch::launch_on_raydium(&launch_accounts, &ctx.accounts.config, &position, token_amount_max, wsol_amount_max)?;
+        let launch_completed = launch::complete_launch(
+            &launch_accounts,
+            &ctx.accounts.config,
+            &mut ctx.accounts.launch_record,
+            ctx.bumps.launch_record,
+            &position,
+            ctx.accounts.token_mint.supply,
+            token_balance_at_start,
+            wsol_deposited,
         )?;
-        // Create pool:
-        raydium::create_pool(
-            CpiContext::new(
-                ctx.accounts.clmm_program.to_account_info(),
-                raydium::CreatePool {
-                    pool_creator: ctx.accounts.payer.to_account_info(),
-                    amm_config_id: ctx.accounts.amm_config_id.to_account_info(),
-                    pool_id: ctx.accounts.pool_id.to_account_info(),
-                    mint_a: ctx.accounts.token_mint.to_account_info(),
-                    mint_b: ctx.accounts.wsol_mint.to_account_info(),
-                    mint_vault_a: ctx.accounts.token_vault.to_account_info(),
-                    mint_vault_b: ctx.accounts.wsol_vault.to_account_info(),
-                    observation_id: ctx.accounts.observation_id.to_account_info(),
-                    ex_tick_array_bitmap: ctx.accounts.ex_tick_array_bitmap.to_account_info(),
-                    mint_program_id_a: ctx.accounts.token_program.to_account_info(),
-                    mint_program_id_b: ctx.accounts.token_program.to_account_info(),
-                    system_program: ctx.accounts.system_program.to_account_info(),
-                    rent_program: ctx.accounts.rent_program.to_account_info(),
-                },
-            ),
-            sqrt_price_x64,
+        emit_cpi!(launch_completed);
+        Ok(())
+    }
+
+    pub fn add_to_raydium_by_market_cap(
+        ctx: Context<AddToRaydiumAccounts>,
+        initial_fdv_lamports: u64,
+        upper_fdv_lamports: u64,
+        token_amount: u64,
+    ) -> Result<()> {
+        let token_balance_at_start = ctx.accounts.token_account.amount;
+        launch::check_mint_invariants(
+            &ctx.accounts.token_mint,
+            &ctx.accounts.token_metadata_account,
+            &ctx.accounts.symbol_registry,
+            token_balance_at_start,
         )?;
-        // Open position:
-        raydium::open_position(
-            CpiContext::new(
-                ctx.accounts.clmm_program.to_account_info(),
-                raydium::OpenPosition {
-                    payer: ctx.accounts.payer.to_account_info(),
-                    position_nft_owner: ctx.accounts.position_nft_owner_pda.to_account_info(),
-                    position_nft_mint: ctx.accounts.position_nft_mint.to_account_info(),
-                    position_nft_account: ctx.accounts.position_nft_account.to_account_info(),
-                    metadata_account: ctx.accounts.metadata_account.to_account_info(),
-                    pool_id: ctx.accounts.pool_id.to_account_info(),
-                    protocol_position: ctx.accounts.protocol_position.to_account_info(),
-                    tick_array_lower: ctx.accounts.tick_array_lower.to_account_info(),
-                    tick_array_upper: ctx.accounts.tick_array_upper.to_account_info(),
-                    personal_position: ctx.accounts.personal_position.to_account_info(),
-                    owner_token_account_a: ctx.accounts.token_account.to_account_info(),
-                    owner_token_account_b: ctx.accounts.wsol_account.to_account_info(),
-                    token_vault_a: ctx.accounts.token_vault.to_account_info(),
-                    token_vault_b: ctx.accounts.wsol_vault.to_account_info(),
-                    rent_program: ctx.accounts.rent_program.to_account_info(),
-                    system_program: ctx.accounts.system_program.to_account_info(),
-                    token_program: ctx.accounts.token_program.to_account_info(),
-                    associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
-                    metadata_program: ctx.accounts.metadata_program.to_account_info(),
-                    token_2022_program: ctx.accounts.token_2022_program.to_account_info(),
-                    token_mint_a: ctx.accounts.token_mint.to_account_info(),
-                    token_mint_b: ctx.accounts.wsol_mint.to_account_info(),
-                },
-            ),
-            tick_lower_index,
-            tick_upper_index,
-            tick_array_lower_start_index,
-            tick_array_upper_start_index,
-            liquidity,
-            token_amount_max,
-            wsol_amount_max,
-            true,   // with_metadata
-            0,      // option_base_flag
-            false,  // base_flag
+        let launch_accounts = ctx.accounts.launch_accounts()?;
+        let token_is_mint_a = launch::token_is_mint_a(&ctx.accounts.token_mint.key(), launch_accounts.wsol_mint.key);
+        let tick_spacing = raydium::read_tick_spacing(&launch_accounts.amm_config_id, launch_accounts.clmm_program.key)?;
+        let position = math::position_from_market_cap(
+            token_is_mint_a,
+            initial_fdv_lamports,
+            upper_fdv_lamports,
+            ctx.accounts.token_mint.supply,
+            token_amount,
+            tick_spacing,
         )?;
-        // Close temporary SOL account:
-        token::close_account(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                CloseAccount {
-                    account: ctx.accounts.wsol_account.to_account_info(),
-                    destination: ctx.accounts.payer.to_account_info(),
-                    authority: ctx.accounts.payer.to_account_info(),
-                },
-            )
+        // Position is made of tokens only:
+        let wsol_deposited = launch::launch_on_raydium(&launch_accounts, &ctx.accounts.config, &position, token_amount, 0)?;
+        let launch_completed = launch::complete_launch(
+            &launch_accounts,
+            &ctx.accounts.config,
+            &mut ctx.accounts.launch_record,
+            ctx.bumps.launch_record,
+            &position,
+            ctx.accounts.token_mint.supply,
+            token_balance_at_start,
+            wsol_deposited,
         )?;
-        // Fee:
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.payer.to_account_info(),
-                    to: ctx.accounts.fee_receiver.to_account_info(),
-                },
-            ),
-            FEE_AMOUNT
+        emit_cpi!(launch_completed);
+        Ok(())
+    }
+
+    pub fn create_and_launch(
+        ctx: Context<CreateAndLaunchAccounts>,
+        amount: u64,
+        decimals: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+        sqrt_price_x64: u128,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        liquidity: u128,
+        token_amount_max: u64,
+        wsol_amount_max: u64,
+    ) -> Result<()> {
+        launch::check_token_supply(amount, decimals, &ctx.accounts.config)?;
+        let TokenMetadataArgs { name, symbol, uri } = metadata::normalize_metadata(&name, &symbol, &uri)?;
+        registry::register_symbol(
+            RegisterSymbolAccounts {
+                payer: ctx.accounts.payer.to_account_info(),
+                token_mint: ctx.accounts.token_mint.to_account_info(),
+                symbol_registry: ctx.accounts.symbol_registry.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &symbol,
+        )?;
+        launch::mint_token(
+            MintTokenAccounts {
+                payer: ctx.accounts.payer.to_account_info(),
+                token_mint: ctx.accounts.token_mint.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                token_metadata_account: ctx.accounts.token_metadata_account.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                metadata_program: ctx.accounts.metadata_program.to_account_info(),
+                rent_program: ctx.accounts.rent_program.to_account_info(),
+            },
+            amount,
+            name.clone(),
+            symbol.clone(),
+            uri.clone(),
         )?;
-        // Require that at least 95% of total supply was provided into liquidity
-        ctx.accounts.token_account.reload()?;
-        let token_amount_spent: u64 = token_balance_at_start - ctx.accounts.token_account.amount;
-        require!(token_amount_spent >= ctx.accounts.token_mint.supply / 100 * MIN_LIQUIDITY_PCT_OF_SUPPLY, CustomError::InsufficientLiquidityProvided);
+        emit_cpi!(TokenCreated {
+            token_mint: ctx.accounts.token_mint.key(),
+            creator: ctx.accounts.payer.key(),
+            name,
+            symbol,
+            uri,
+            supply: amount,
+            decimals: ctx.accounts.token_mint.decimals,
+        });
+        let token_is_mint_a = launch::token_is_mint_a(&ctx.accounts.token_mint.key(), ctx.accounts.raydium.wsol_mint.key);
+        let (sqrt_price_x64, tick_lower_index, tick_upper_index) =
+            launch::to_pool_orientation(token_is_mint_a, sqrt_price_x64, tick_lower_index, tick_upper_index)?;
+        let position = math::PoolPosition { sqrt_price_x64, tick_lower_index, tick_upper_index, liquidity };
+        let launch_accounts = ctx.accounts.launch_accounts()?;
+        let wsol_deposited = launch::launch_on_raydium(&launch_accounts, &ctx.accounts.config, &position, token_amount_max, wsol_amount_max)?;
+        // Whole supply was minted to payer within this instruction, so whatever is left was not provided:
+        let launch_completed = launch::complete_launch(
+            &launch_accounts,
+            &ctx.accounts.config,
+            &mut ctx.accounts.launch_record,
+            ctx.bumps.launch_record,
+            &position,
+            amount,
+            amount,
+            wsol_deposited,
+        )?;
+        emit_cpi!(launch_completed);
         Ok(())
     }
 
     pub fn harvest_fees<'info>(ctx: Context<'_, '_, '_, 'info, HarvestAccounts<'info>>) -> Result<()> {
-        // Create, initialize and fund temporary WSOL account:
-        anchor_lang::system_program::create_account(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                CreateAccount {
-                    from: ctx.accounts.payer.to_account_info(),
-                    to: ctx.accounts.wsol_recipient_account.to_account_info(),
-                },
-            ),
-            ctx.accounts.rent_program.minimum_balance(TOKEN_ACCOUNT_SPACE as usize),
-            TOKEN_ACCOUNT_SPACE,
-            &ctx.accounts.token_program.key(),
+        let mut launch_record = ctx.accounts.read_launch_record()?;
+        let token_balance_at_start = ctx.accounts.token_recipient_account.amount;
+        let common_accounts = ctx.accounts.common_accounts();
+        harvest::create_wsol_account(&common_accounts)?;
+        harvest::harvest_position(
+            &common_accounts,
+            ctx.bumps.position_nft_owner_pda,
+            &ctx.accounts.position_nft_account.to_account_info(),
+            &ctx.accounts.personal_position,
+            &ctx.accounts.pool_id,
+            &ctx.accounts.protocol_position,
+            &ctx.accounts.token_vault,
+            &ctx.accounts.wsol_vault,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            &ctx.accounts.token_mint.to_account_info(),
+            &ctx.accounts.token_recipient_account.to_account_info(),
+            ctx.remaining_accounts,
         )?;
-        token::initialize_account(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                InitializeAccount {
-                    account: ctx.accounts.wsol_recipient_account.to_account_info(),
-                    mint: ctx.accounts.wsol_mint.to_account_info(),
-                    authority: ctx.accounts.payer.to_account_info(),
-                    rent: ctx.accounts.rent_program.to_account_info(),
-                }
-            )
-        )?;
-        // Harvest:
-        let harvest_accounts: DecreaseLiquidityV2<'_> = raydium::DecreaseLiquidityV2 {
-            position_nft_owner: ctx.accounts.position_nft_owner_pda.to_account_info(),
-            position_nft_account: ctx.accounts.position_nft_account.to_account_info(),
-            personal_position: ctx.accounts.personal_position.to_account_info(),
-            pool_state: ctx.accounts.pool_id.to_account_info(),
-            protocol_position: ctx.accounts.protocol_position.to_account_info(),
-            token_vault_0: ctx.accounts.token_vault.to_account_info(),
-            token_vault_1: ctx.accounts.wsol_vault.to_account_info(),
-            tick_array_lower: ctx.accounts.tick_array_lower.to_account_info(),
-            tick_array_upper: ctx.accounts.tick_array_upper.to_account_info(),
-            recipient_token_account_0: ctx.accounts.token_recipient_account.to_account_info(),
-            recipient_token_account_1: ctx.accounts.wsol_recipient_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            token_2022_program: ctx.accounts.token_2022_program.to_account_info(),
-            memo_program: ctx.accounts.memo_program.to_account_info(),
-            vault_0_mint: ctx.accounts.token_mint.to_account_info(),
-            vault_1_mint: ctx.accounts.wsol_mint.to_account_info(),
+
+        // Record harvested amounts:
+        ctx.accounts.token_recipient_account.reload()?;
+        let token_harvested = harvest::amount_harvested(token_balance_at_start, ctx.accounts.token_recipient_account.amount)?;
+        let wsol_harvested = token::accessor::amount(&ctx.accounts.wsol_recipient_account)?;
+        let (creator_token_share, creator_wsol_share) = match &mut launch_record {
+            Some(launch_record) => {
+                harvest::record_harvest(launch_record, token_harvested, wsol_harvested)?;
+                ctx.accounts.write_launch_record(launch_record)?;
+                let creator_fee_share_bps = ctx.accounts.config.creator_fee_share_bps;
+                (fee_share(token_harvested, creator_fee_share_bps), fee_share(wsol_harvested, creator_fee_share_bps))
+            }
+            // Positions launched before launch records have no known creator:
+            None => (0, 0),
         };
+        ctx.accounts.pay_creator_token_share(launch_record.as_ref(), creator_token_share, ctx.bumps.creator_escrow)?;
+        harvest::transfer_tokens(
+            &ctx.accounts.token_mint_program.to_account_info(),
+            &ctx.accounts.token_recipient_account.to_account_info(),
+            &ctx.accounts.token_mint.to_account_info(),
+            &ctx.accounts.fee_receiver_token_account.to_account_info(),
+            &common_accounts.payer,
+            harvest::amount_after_share(token_harvested, creator_token_share)?,
+            ctx.accounts.token_mint.decimals,
+            &[],
+        )?;
+
+        harvest::close_wsol_account(&common_accounts)?;
+        // WSOL was unwrapped to payer, so shares are paid in SOL and payer keeps the keeper tip:
+        ctx.accounts.pay_creator_wsol_share(launch_record.as_ref(), creator_wsol_share, ctx.bumps.creator_escrow)?;
+        let fee_receiver_wsol_share = harvest::amount_after_share(wsol_harvested, creator_wsol_share)?;
+        let keeper_tip = fee_receiver_wsol_share.min(ctx.accounts.config.keeper_tip_lamports);
+        harvest::transfer_lamports(
+            &common_accounts.system_program,
+            &common_accounts.payer,
+            &common_accounts.fee_receiver,
+            harvest::amount_after_share(fee_receiver_wsol_share, keeper_tip)?,
+        )?;
+        emit_cpi!(FeesHarvested {
+            token_mint: ctx.accounts.token_mint.key(),
+            pool_id: ctx.accounts.pool_id.key(),
+            position_nft_mint: ctx.accounts.position_nft_mint.key(),
+            token_harvested,
+            wsol_harvested,
+            creator_token_share,
+            creator_wsol_share,
+        });
+        Ok(())
+    }
 
-        let bump = ctx.bumps.position_nft_owner_pda;
-        let seeds = &[b"position_nft_owner_pda".as_ref(), &[bump]];
+    // Harvests several positions in one instruction. Each position is given by POSITION_ACCOUNTS_LEN
+    // remaining accounts followed by `raydium_remaining_accounts_counts[i]` Raydium remaining accounts.
+    pub fn harvest_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestManyAccounts<'info>>,
+        raydium_remaining_accounts_counts: Vec<u8>,
+    ) -> Result<()> {
+        let common_accounts = ctx.accounts.common_accounts();
+        let creator_fee_share_bps = ctx.accounts.config.creator_fee_share_bps;
+        harvest::create_wsol_account(&common_accounts)?;
+        let mut remaining_accounts = ctx.remaining_accounts;
+        let mut fee_receiver_wsol_share: u64 = 0;
+        let mut creator_wsol_payments = Vec::with_capacity(raydium_remaining_accounts_counts.len());
+        for raydium_remaining_accounts_count in raydium_remaining_accounts_counts {
+            let group_len = harvest::POSITION_ACCOUNTS_LEN + raydium_remaining_accounts_count as usize;
+            require!(remaining_accounts.len() >= group_len, CustomError::InvalidPositionAccounts);
+            let (group, rest) = remaining_accounts.split_at(group_len);
+            let (position_accounts, raydium_remaining_accounts) = group.split_at(harvest::POSITION_ACCOUNTS_LEN);
+            let position_harvest = harvest::harvest_position_group(
+                &common_accounts,
+                ctx.bumps.position_nft_owner_pda,
+                creator_fee_share_bps,
+                position_accounts,
+                raydium_remaining_accounts,
+            )?;
+            let position_fee_receiver_wsol_share =
+                harvest::amount_after_share(position_harvest.event.wsol_harvested, position_harvest.event.creator_wsol_share)?;
+            fee_receiver_wsol_share = fee_receiver_wsol_share
+                .checked_add(position_fee_receiver_wsol_share)
+                .ok_or(CustomError::ArithmeticOverflow)?;
+            emit_cpi!(position_harvest.event);
+            creator_wsol_payments.push((position_harvest.creator_wsol_destination, position_harvest.event.creator_wsol_share));
+            remaining_accounts = rest;
+        }
+        require!(remaining_accounts.is_empty(), CustomError::InvalidPositionAccounts);
+
+        harvest::close_wsol_account(&common_accounts)?;
+        // WSOL was unwrapped to payer, so shares are paid in SOL and payer keeps one keeper tip:
+        for (creator_wsol_destination, creator_wsol_share) in creator_wsol_payments {
+            harvest::transfer_lamports(
+                &common_accounts.system_program,
+                &common_accounts.payer,
+                &creator_wsol_destination,
+                creator_wsol_share,
+            )?;
+        }
+        let keeper_tip = fee_receiver_wsol_share.min(ctx.accounts.config.keeper_tip_lamports);
+        harvest::transfer_lamports(
+            &common_accounts.system_program,
+            &common_accounts.payer,
+            &common_accounts.fee_receiver,
+            harvest::amount_after_share(fee_receiver_wsol_share, keeper_tip)?,
+        )
+    }
+
+    // Collects Token-2022 transfer fees withheld in token accounts passed in remaining accounts (such as
+    // Raydium vaults) into the mint, withdraws all fees withheld in the mint and splits them between
+    // the creator and fee receiver
+    pub fn harvest_withheld_fees<'info>(ctx: Context<'_, '_, '_, 'info, HarvestWithheldFeesAccounts<'info>>) -> Result<()> {
+        require_keys_eq!(ctx.accounts.creator.key(), ctx.accounts.creator_of_token()?, CustomError::LaunchRecordMismatch);
+        if !ctx.remaining_accounts.is_empty() {
+            token_interface::harvest_withheld_tokens_to_mint(
+                CpiContext::new(
+                    ctx.accounts.token_2022_program.to_account_info(),
+                    token_interface::HarvestWithheldTokensToMint {
+                        token_program_id: ctx.accounts.token_2022_program.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                    },
+                ),
+                ctx.remaining_accounts.to_vec(),
+            )?;
+        }
+        let seeds = &[WITHHELD_FEE_AUTHORITY_SEED, &[ctx.bumps.withheld_fee_authority]];
         let signer_seeds = &[&seeds[..]];
+        token_interface::withdraw_withheld_tokens_from_mint(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_2022_program.to_account_info(),
+                token_interface::WithdrawWithheldTokensFromMint {
+                    token_program_id: ctx.accounts.token_2022_program.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    destination: ctx.accounts.withheld_fee_vault.to_account_info(),
+                    authority: ctx.accounts.withheld_fee_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+        )?;
 
-        let cpi_ctx: CpiContext<'_, '_, '_, '_, DecreaseLiquidityV2<'_>> = CpiContext::new_with_signer(
-            ctx.accounts.clmm_program.to_account_info(),
-            harvest_accounts,
+        // Split whole vault balance, transfers from the vault are subject to the transfer fee themselves:
+        ctx.accounts.withheld_fee_vault.reload()?;
+        let amount = ctx.accounts.withheld_fee_vault.amount;
+        let creator_share = fee_share(amount, ctx.accounts.config.creator_withheld_fee_share_bps);
+        let protocol_share = harvest::amount_after_share(amount, creator_share)?;
+        let decimals = ctx.accounts.token_mint.decimals;
+        harvest::transfer_tokens(
+            &ctx.accounts.token_2022_program.to_account_info(),
+            &ctx.accounts.withheld_fee_vault.to_account_info(),
+            &ctx.accounts.token_mint.to_account_info(),
+            &ctx.accounts.creator_token_account.to_account_info(),
+            &ctx.accounts.withheld_fee_authority.to_account_info(),
+            creator_share,
+            decimals,
+            signer_seeds,
+        )?;
+        harvest::transfer_tokens(
+            &ctx.accounts.token_2022_program.to_account_info(),
+            &ctx.accounts.withheld_fee_vault.to_account_info(),
+            &ctx.accounts.token_mint.to_account_info(),
+            &ctx.accounts.fee_receiver_token_account.to_account_info(),
+            &ctx.accounts.withheld_fee_authority.to_account_info(),
+            protocol_share,
+            decimals,
             signer_seeds,
-        ).with_remaining_accounts(ctx.remaining_accounts.to_vec());
-
-        raydium::harvest(cpi_ctx)?;
-
-        // Close temporary WSOL account:
-        token::close_account(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                CloseAccount {
-                    account: ctx.accounts.wsol_recipient_account.to_account_info(),
-                    destination: ctx.accounts.payer.to_account_info(),
-                    authority: ctx.accounts.payer.to_account_info(),
-                },
-            )
         )?;
+        emit_cpi!(WithheldFeesHarvested {
+            token_mint: ctx.accounts.token_mint.key(),
+            creator: ctx.accounts.creator.key(),
+            amount,
+            creator_share,
+            protocol_share,
+        });
+        Ok(())
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFeesAccounts>) -> Result<()> {
+        let token_mint_key = ctx.accounts.token_mint.key();
+        let seeds = &[CREATOR_ESCROW_SEED, token_mint_key.as_ref(), &[ctx.accounts.creator_escrow.bump]];
+        let signer_seeds = &[&seeds[..]];
+        // Tokens, if escrow ever received them:
+        if let Some(creator_escrow_token_account) = &ctx.accounts.creator_escrow_token_account {
+            let token_amount = creator_escrow_token_account.amount;
+            if token_amount > 0 {
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        token_interface::TransferChecked {
+                            from: creator_escrow_token_account.to_account_info(),
+                            mint: ctx.accounts.token_mint.to_account_info(),
+                            to: ctx.accounts.creator_token_account.to_account_info(),
+                            authority: ctx.accounts.creator_escrow.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    token_amount,
+                    ctx.accounts.token_mint.decimals,
+                )?;
+            }
+        }
+        // SOL above rent-exempt minimum:
+        let escrow_info = ctx.accounts.creator_escrow.to_account_info();
+        let rent_exempt_lamports = Rent::get()?.minimum_balance(escrow_info.data_len());
+        let lamports = escrow_info.lamports().saturating_sub(rent_exempt_lamports);
+        if lamports > 0 {
+            escrow_info.sub_lamports(lamports)?;
+            ctx.accounts.creator.add_lamports(lamports)?;
+        }
         Ok(())
     }
 }
@@ -311,13 +643,92 @@ pub struct Initialize<'info> {
 }
 
 #[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer=admin,
+        seeds=[LAUNCHER_CONFIG_SEED],
+        bump,
+        space=8 + LauncherConfig::INIT_SPACE
+    )]
+    config: Account<'info, LauncherConfig>,
+
+    #[account(mut)]
+    admin: Signer<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    program: Program<'info, program::TokenDeployer>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ CustomError::Unauthorized)]
+    program_data: Account<'info, ProgramData>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds=[LAUNCHER_CONFIG_SEED],
+        bump=config.bump,
+        has_one=admin @ CustomError::Unauthorized
+    )]
+    config: Account<'info, LauncherConfig>,
+    admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(symbol: String)]
+pub struct ReserveSymbol<'info> {
+    #[account(
+        seeds=[LAUNCHER_CONFIG_SEED],
+        bump=config.bump,
+        has_one=admin @ CustomError::Unauthorized
+    )]
+    config: Account<'info, LauncherConfig>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [SYMBOL_REGISTRY_SEED, metadata::normalize_symbol(&symbol)?.as_bytes()],
+        bump,
+        space = 8 + SymbolRegistry::INIT_SPACE
+    )]
+    symbol_registry: Account<'info, SymbolRegistry>,
+    #[account(mut)]
+    admin: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(symbol: String)]
+pub struct ReleaseSymbol<'info> {
+    #[account(
+        seeds=[LAUNCHER_CONFIG_SEED],
+        bump=config.bump,
+        has_one=admin @ CustomError::Unauthorized
+    )]
+    config: Account<'info, LauncherConfig>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [SYMBOL_REGISTRY_SEED, metadata::normalize_symbol(&symbol)?.as_bytes()],
+        bump = symbol_registry.bump,
+        constraint = symbol_registry.reserved @ CustomError::SymbolNotReserved,
+    )]
+    symbol_registry: Account<'info, SymbolRegistry>,
+    #[account(mut)]
+    admin: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(amount: u64, decimals: u8)]
 pub struct CreateTokenAccounts<'info> {
+    #[account(seeds=[LAUNCHER_CONFIG_SEED], bump=config.bump)]
+    config: Box<Account<'info, LauncherConfig>>,
     #[account(mut)]
     payer: Signer<'info>,
     #[account(
         init,
         payer = payer,
-        mint::decimals = 6,
+        mint::decimals = decimals,
         mint::authority = payer,
     )]
     token_mint: Account<'info, Mint>,
@@ -331,6 +742,9 @@ pub struct CreateTokenAccounts<'info> {
     #[account(mut)]
     /// CHECK: checked in CPI
     token_metadata_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: created for normalized symbol in instruction
+    symbol_registry: UncheckedAccount<'info>,
   
     system_program: Program<'info, System>,
     token_program: Program<'info, Token>,
@@ -339,21 +753,154 @@ pub struct CreateTokenAccounts<'info> {
     rent_program: Sysvar<'info, Rent>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateToken2022Accounts<'info> {
+    #[account(seeds=[LAUNCHER_CONFIG_SEED], bump=config.bump)]
+    config: Box<Account<'info, LauncherConfig>>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(mut)]
+    token_mint: Signer<'info>,  // Created with metadata extensions in instruction
+    #[account(mut)]
+    /// CHECK: payer's associated token account, created in instruction
+    token_account: UncheckedAccount<'info>,
+    #[account(seeds=[WITHHELD_FEE_AUTHORITY_SEED], bump)]
+    /// CHECK: only used as withdraw withheld authority of transfer fee
+    withheld_fee_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: created for normalized symbol in instruction
+    symbol_registry: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token2022>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddToRaydiumAccounts<'info> {
+    #[account(seeds=[LAUNCHER_CONFIG_SEED], bump=config.bump)]
+    config: Box<Account<'info, LauncherConfig>>,
     #[account(mut)]
     payer: Signer<'info>,
-    token_mint: Account<'info, Mint>,
+    token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,  // Legacy or Token-2022 token
     #[account(mut)]
-    token_account: Account<'info, TokenAccount>,
+    token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    /// CHECK: checked in instruction, Metaplex metadata or the mint itself for Token-2022 metadata
+    token_metadata_account: UncheckedAccount<'info>,
+    /// CHECK: checked in instruction to be SymbolRegistry of the normalized metadata symbol registered by the token
+    symbol_registry: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [LAUNCH_RECORD_SEED, token_mint.key().as_ref()],
+        bump,
+        space = 8 + LaunchRecord::INIT_SPACE
+    )]
+    launch_record: Box<Account<'info, LaunchRecord>>,
 
+    raydium: RaydiumLaunchAccounts<'info>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    token_2022_program: Program<'info, Token2022>,
+    metadata_program: Program<'info, Metadata>,
+    rent_program: Sysvar<'info, Rent>,
+}
+
+impl<'info> AddToRaydiumAccounts<'info> {
+    fn launch_accounts(&self) -> Result<LaunchAccounts<'info>> {
+        self.raydium.launch_accounts(
+            &self.config,
+            &self.payer,
+            self.token_mint.to_account_info(),
+            self.token_account.to_account_info(),
+            &self.system_program,
+            &self.token_program,
+            &self.associated_token_program,
+            &self.token_2022_program,
+            &self.metadata_program,
+            &self.rent_program,
+        )
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(amount: u64, decimals: u8)]
+pub struct CreateAndLaunchAccounts<'info> {
+    #[account(seeds=[LAUNCHER_CONFIG_SEED], bump=config.bump)]
+    config: Box<Account<'info, LauncherConfig>>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = decimals,
+        mint::authority = payer,
+    )]
+    token_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = payer
+    )]
+    token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [LAUNCH_RECORD_SEED, token_mint.key().as_ref()],
+        bump,
+        space = 8 + LaunchRecord::INIT_SPACE
+    )]
+    launch_record: Box<Account<'info, LaunchRecord>>,
+    #[account(mut)]
     /// CHECK: checked in CPI
+    token_metadata_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    /// CHECK: created for normalized symbol in instruction
+    symbol_registry: UncheckedAccount<'info>,
+
+    raydium: RaydiumLaunchAccounts<'info>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+    token_2022_program: Program<'info, Token2022>,
+    metadata_program: Program<'info, Metadata>,
+    rent_program: Sysvar<'info, Rent>,
+}
+
+impl<'info> CreateAndLaunchAccounts<'info> {
+    fn launch_accounts(&self) -> Result<LaunchAccounts<'info>> {
+        self.raydium.launch_accounts(
+            &self.config,
+            &self.payer,
+            self.token_mint.to_account_info(),
+            self.token_account.to_account_info(),
+            &self.system_program,
+            &self.token_program,
+            &self.associated_token_program,
+            &self.token_2022_program,
+            &self.metadata_program,
+            &self.rent_program,
+        )
+    }
+}
+
+// Raydium pool and position accounts shared by add_to_raydium, add_to_raydium_by_market_cap and create_and_launch.
+// Accounts fixed by launcher config are checked by launch_accounts, as constraints can not refer to the outer config.
+#[derive(Accounts)]
+pub struct RaydiumLaunchAccounts<'info> {
+    /// CHECK: checked to be owned by clmm_program, tick spacing is read in instruction
     amm_config_id: UncheckedAccount<'info>,
     #[account(mut)]
     /// CHECK: checked in CPI
     pool_id: UncheckedAccount<'info>,
 
-    /// CHECK: checked in CPI
+    #[account(address = token::spl_token::native_mint::ID @ CustomError::InvalidWsolMint)]
+    /// CHECK: checked by address
     wsol_mint: UncheckedAccount<'info>,
 
     #[account(mut)]
@@ -363,7 +910,7 @@ pub struct AddToRaydiumAccounts<'info> {
     /// CHECK: checked in CPI
     wsol_vault: UncheckedAccount<'info>,
     #[account(mut)]
-    /// CHECK: checked in CPI
+    /// CHECK: checked by owner and size, initialized in CPI
     observation_id: UncheckedAccount<'info>,
     #[account(mut)]
     /// CHECK: checked in CPI
@@ -375,7 +922,7 @@ pub struct AddToRaydiumAccounts<'info> {
     /// CHECK: only used as a signing PDA
     position_nft_owner_pda: AccountInfo<'info>,
     #[account(mut)]
-    /// CHECK: checked in CPI
+    /// CHECK: created in CPI, checked to be position_nft_owner_pda's associated token account after CPI
     position_nft_account: UncheckedAccount<'info>,
     #[account(mut)]
     /// CHECK: checked in CPI
@@ -395,23 +942,73 @@ pub struct AddToRaydiumAccounts<'info> {
     #[account(mut)]
     wsol_account: Signer<'info>,
 
-    #[account(mut, address = HARVESTER)]
-    /// CHECK: checked by address
+    #[account(mut)]
+    /// CHECK: checked against launcher config in instruction
     fee_receiver: UncheckedAccount<'info>,
     
-    #[account(address = CLMM_PROGRAM_ID)]
-    /// CHECK: checked by address
+    /// CHECK: checked against launcher config in instruction
     clmm_program: UncheckedAccount<'info>,
-    system_program: Program<'info, System>,
-    token_program: Program<'info, Token>,
-    associated_token_program: Program<'info, AssociatedToken>,
-    token_2022_program: Program<'info, Token2022>,
-    metadata_program: Program<'info, Metadata>,
-    rent_program: Sysvar<'info, Rent>,
 }
 
+impl<'info> RaydiumLaunchAccounts<'info> {
+    fn launch_accounts(
+        &self,
+        config: &LauncherConfig,
+        payer: &Signer<'info>,
+        token_mint: AccountInfo<'info>,
+        token_account: AccountInfo<'info>,
+        system_program: &Program<'info, System>,
+        token_program: &Program<'info, Token>,
+        associated_token_program: &Program<'info, AssociatedToken>,
+        token_2022_program: &Program<'info, Token2022>,
+        metadata_program: &Program<'info, Metadata>,
+        rent_program: &Sysvar<'info, Rent>,
+    ) -> Result<LaunchAccounts<'info>> {
+        require_keys_eq!(self.clmm_program.key(), config.clmm_program_id, CustomError::InvalidClmmProgram);
+        require_keys_eq!(self.fee_receiver.key(), config.fee_receiver, CustomError::InvalidFeeReceiver);
+        let token_mint_program = if token_mint.owner == token_2022_program.key {
+            token_2022_program.to_account_info()
+        } else {
+            token_program.to_account_info()
+        };
+        Ok(LaunchAccounts {
+            payer: payer.to_account_info(),
+            token_mint,
+            token_account,
+            amm_config_id: self.amm_config_id.to_account_info(),
+            pool_id: self.pool_id.to_account_info(),
+            wsol_mint: self.wsol_mint.to_account_info(),
+            token_vault: self.token_vault.to_account_info(),
+            wsol_vault: self.wsol_vault.to_account_info(),
+            observation_id: self.observation_id.to_account_info(),
+            ex_tick_array_bitmap: self.ex_tick_array_bitmap.to_account_info(),
+            position_nft_mint: self.position_nft_mint.to_account_info(),
+            position_nft_owner_pda: self.position_nft_owner_pda.to_account_info(),
+            position_nft_account: self.position_nft_account.to_account_info(),
+            metadata_account: self.metadata_account.to_account_info(),
+            protocol_position: self.protocol_position.to_account_info(),
+            tick_array_lower: self.tick_array_lower.to_account_info(),
+            tick_array_upper: self.tick_array_upper.to_account_info(),
+            personal_position: self.personal_position.to_account_info(),
+            wsol_account: self.wsol_account.to_account_info(),
+            fee_receiver: self.fee_receiver.to_account_info(),
+            clmm_program: self.clmm_program.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+            token_mint_program,
+            associated_token_program: associated_token_program.to_account_info(),
+            token_2022_program: token_2022_program.to_account_info(),
+            metadata_program: metadata_program.to_account_info(),
+            rent_program: rent_program.to_account_info(),
+        })
+    }
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct HarvestAccounts<'info> {
+    #[account(seeds=[LAUNCHER_CONFIG_SEED], bump=config.bump)]
+    config: Box<Account<'info, LauncherConfig>>,
     #[account(mut, seeds=[b"position_nft_owner_pda"], bump)]
     /// CHECK: only used as a signing PDA
     position_nft_owner_pda: AccountInfo<'info>,
@@ -421,6 +1018,24 @@ pub struct HarvestAccounts<'info> {
     )]
     position_nft_account: Box<Account<'info, TokenAccount>>,
     position_nft_mint: Box<Account<'info, Mint>>,
+    #[account(mut, seeds = [LAUNCH_RECORD_SEED, token_mint.key().as_ref()], bump)]
+    /// CHECK: checked by seeds, empty only for positions launched before launch records (see read_launch_record)
+    launch_record: UncheckedAccount<'info>,
+
+    // Creator's share is paid to creator's accounts, or to escrow when they do not exist.
+    // Required when launch_record exists; escrow accounts are only required, and created, when used.
+    #[account(mut)]
+    /// CHECK: checked against launch record
+    creator: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    /// CHECK: checked to be creator's associated token account, may be uninitialized
+    creator_token_account: Option<UncheckedAccount<'info>>,
+    #[account(mut, seeds = [CREATOR_ESCROW_SEED, token_mint.key().as_ref()], bump)]
+    /// CHECK: checked by seeds, created by the program when creator's share is first paid into it
+    creator_escrow: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    /// CHECK: checked to be escrow's associated token account, created when creator's token share is first paid into it
+    creator_escrow_token_account: Option<UncheckedAccount<'info>>,
 
     #[account(mut)]
     /// CHECK: checked in CPI
@@ -449,31 +1064,421 @@ pub struct HarvestAccounts<'info> {
         payer=payer,
         associated_token::mint=token_mint,
         associated_token::authority=payer,
+        associated_token::token_program=token_mint_program,
     )]
-    token_recipient_account: Box<Account<'info, TokenAccount>>,
+    token_recipient_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
     #[account(mut)]
     wsol_recipient_account: Signer<'info>,
     
-    token_mint: Account<'info, Mint>,
+    token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,  // Legacy or Token-2022 token
+    #[account(address = token::spl_token::native_mint::ID @ CustomError::InvalidWsolMint)]
     wsol_mint: Account<'info, Mint>,
 
-    #[account(mut, address = HARVESTER)]
+    #[account(mut, address = config.fee_receiver)]
+    /// CHECK: checked by address
+    fee_receiver: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer=payer,
+        associated_token::mint=token_mint,
+        associated_token::authority=fee_receiver,
+        associated_token::token_program=token_mint_program,
+    )]
+    fee_receiver_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    // Anyone can harvest, fees are routed to fee receiver and creator
+    #[account(mut)]
     payer: Signer<'info>,
     
-    #[account(address = CLMM_PROGRAM_ID)]
+    #[account(address = config.clmm_program_id)]
     /// CHECK: checked by address
     clmm_program: UncheckedAccount<'info>,
     system_program: Program<'info, System>,
     token_program: Program<'info, Token>,
     associated_token_program: Program<'info, AssociatedToken>,
     token_2022_program: Program<'info, Token2022>,
-    /// CHECK: checked in CPI
+    #[account(address = *token_mint.to_account_info().owner)]
+    token_mint_program: Interface<'info, TokenInterface>,
+    #[account(address = MEMO_PROGRAM_ID @ CustomError::InvalidMemoProgram)]
+    /// CHECK: checked by address
+    memo_program: UncheckedAccount<'info>,
+    rent_program: Sysvar<'info, Rent>,
+}
+
+impl<'info> HarvestAccounts<'info> {
+    fn common_accounts(&self) -> harvest::HarvestCommonAccounts<'info> {
+        harvest::HarvestCommonAccounts {
+            payer: self.payer.to_account_info(),
+            position_nft_owner_pda: self.position_nft_owner_pda.to_account_info(),
+            wsol_recipient_account: self.wsol_recipient_account.to_account_info(),
+            wsol_mint: self.wsol_mint.to_account_info(),
+            fee_receiver: self.fee_receiver.to_account_info(),
+            clmm_program: self.clmm_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_2022_program: self.token_2022_program.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+            rent_program: self.rent_program.to_account_info(),
+        }
+    }
+
+    // Reads launch record of the position. It may only be missing (PDA without data) for positions launched
+    // before launch records, otherwise creator's share and harvested totals could be skipped.
+    // Lamports alone are ignored, as anyone can transfer them to the PDA.
+    fn read_launch_record(&self) -> Result<Option<LaunchRecord>> {
+        let launch_record_info = self.launch_record.to_account_info();
+        if launch_record_info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*launch_record_info.owner, crate::ID, CustomError::LaunchRecordMismatch);
+        let launch_record = LaunchRecord::try_deserialize(&mut &launch_record_info.try_borrow_data()?[..])?;
+        require_keys_eq!(launch_record.position_nft_mint, self.position_nft_mint.key(), CustomError::LaunchRecordMismatch);
+        require_keys_eq!(launch_record.pool_id, self.pool_id.key(), CustomError::LaunchRecordMismatch);
+        self.creator(Some(&launch_record))?;
+        Ok(Some(launch_record))
+    }
+
+    fn write_launch_record(&self, launch_record: &LaunchRecord) -> Result<()> {
+        launch_record.try_serialize(&mut &mut self.launch_record.try_borrow_mut_data()?[..])
+    }
+
+    // Checks creator accounts against launch record and returns creator
+    fn creator(&self, launch_record: Option<&LaunchRecord>) -> Result<&UncheckedAccount<'info>> {
+        let launch_record = launch_record.ok_or(CustomError::LaunchRecordMismatch)?;
+        let creator = self.creator.as_ref().ok_or(CustomError::MissingCreatorAccounts)?;
+        require_keys_eq!(creator.key(), launch_record.creator, CustomError::LaunchRecordMismatch);
+        Ok(creator)
+    }
+
+    // Returns creator's escrow, creating it on first use
+    fn creator_escrow(&self, launch_record: Option<&LaunchRecord>, bump: Option<u8>) -> Result<AccountInfo<'info>> {
+        let creator = self.creator(launch_record)?.key();
+        let creator_escrow = self.creator_escrow.as_ref().ok_or(CustomError::MissingCreatorAccounts)?.to_account_info();
+        harvest::init_creator_escrow_if_needed(
+            &self.common_accounts(),
+            &creator_escrow,
+            &creator,
+            &self.token_mint.key(),
+            bump.ok_or(CustomError::MissingCreatorAccounts)?,
+        )?;
+        Ok(creator_escrow)
+    }
+
+    fn pay_creator_token_share(&self, launch_record: Option<&LaunchRecord>, amount: u64, escrow_bump: Option<u8>) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let creator = self.creator(launch_record)?.key();
+        let creator_token_account = self.creator_token_account.as_ref().ok_or(CustomError::MissingCreatorAccounts)?;
+        require_keys_eq!(
+            creator_token_account.key(),
+            get_associated_token_address_with_program_id(&creator, &self.token_mint.key(), self.token_mint_program.key),
+            CustomError::LaunchRecordMismatch
+        );
+        let destination = if harvest::is_initialized_token_account(creator_token_account, self.token_mint_program.key) {
+            creator_token_account.to_account_info()
+        } else {
+            let creator_escrow = self.creator_escrow(launch_record, escrow_bump)?;
+            let creator_escrow_token_account = self.creator_escrow_token_account.as_ref().ok_or(CustomError::MissingCreatorAccounts)?;
+            require_keys_eq!(
+                creator_escrow_token_account.key(),
+                get_associated_token_address_with_program_id(creator_escrow.key, &self.token_mint.key(), self.token_mint_program.key),
+                CustomError::LaunchRecordMismatch
+            );
+            associated_token::create_idempotent(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: self.payer.to_account_info(),
+                    associated_token: creator_escrow_token_account.to_account_info(),
+                    authority: creator_escrow,
+                    mint: self.token_mint.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    token_program: self.token_mint_program.to_account_info(),
+                },
+            ))?;
+            creator_escrow_token_account.to_account_info()
+        };
+        harvest::transfer_tokens(
+            &self.token_mint_program.to_account_info(),
+            &self.token_recipient_account.to_account_info(),
+            &self.token_mint.to_account_info(),
+            &destination,
+            &self.payer.to_account_info(),
+            amount,
+            self.token_mint.decimals,
+            &[],
+        )
+    }
+
+    fn pay_creator_wsol_share(&self, launch_record: Option<&LaunchRecord>, amount: u64, escrow_bump: Option<u8>) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let creator = self.creator(launch_record)?.to_account_info();
+        // Transfers below rent-exempt minimum to a non-existent account would fail:
+        let destination = if creator.lamports() > 0 {
+            creator
+        } else {
+            self.creator_escrow(launch_record, escrow_bump)?
+        };
+        harvest::transfer_lamports(
+            &self.system_program.to_account_info(),
+            &self.payer.to_account_info(),
+            &destination,
+            amount,
+        )
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct HarvestManyAccounts<'info> {
+    #[account(seeds=[LAUNCHER_CONFIG_SEED], bump=config.bump)]
+    config: Box<Account<'info, LauncherConfig>>,
+    #[account(mut, seeds=[b"position_nft_owner_pda"], bump)]
+    /// CHECK: only used as a signing PDA
+    position_nft_owner_pda: AccountInfo<'info>,
+
+    #[account(mut)]
+    wsol_recipient_account: Signer<'info>,
+    #[account(address = token::spl_token::native_mint::ID @ CustomError::InvalidWsolMint)]
+    wsol_mint: Account<'info, Mint>,
+
+    #[account(mut, address = config.fee_receiver)]
+    /// CHECK: checked by address
+    fee_receiver: UncheckedAccount<'info>,
+
+    // Anyone can harvest, fees are routed to fee receiver and creators
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    #[account(address = config.clmm_program_id)]
+    /// CHECK: checked by address
+    clmm_program: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    token_2022_program: Program<'info, Token2022>,
+    #[account(address = MEMO_PROGRAM_ID @ CustomError::InvalidMemoProgram)]
+    /// CHECK: checked by address
     memo_program: UncheckedAccount<'info>,
     rent_program: Sysvar<'info, Rent>,
 }
 
+impl<'info> HarvestManyAccounts<'info> {
+    fn common_accounts(&self) -> harvest::HarvestCommonAccounts<'info> {
+        harvest::HarvestCommonAccounts {
+            payer: self.payer.to_account_info(),
+            position_nft_owner_pda: self.position_nft_owner_pda.to_account_info(),
+            wsol_recipient_account: self.wsol_recipient_account.to_account_info(),
+            wsol_mint: self.wsol_mint.to_account_info(),
+            fee_receiver: self.fee_receiver.to_account_info(),
+            clmm_program: self.clmm_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            token_2022_program: self.token_2022_program.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+            rent_program: self.rent_program.to_account_info(),
+        }
+    }
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct HarvestWithheldFeesAccounts<'info> {
+    #[account(seeds=[LAUNCHER_CONFIG_SEED], bump=config.bump)]
+    config: Box<Account<'info, LauncherConfig>>,
+    #[account(mut, mint::token_program = token_2022_program)]
+    token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(seeds = [LAUNCH_RECORD_SEED, token_mint.key().as_ref()], bump)]
+    /// CHECK: checked by seeds, empty for tokens that were not launched (see creator_of_token)
+    launch_record: UncheckedAccount<'info>,
+    // Registers the token's symbol, required only when the token was not launched
+    symbol_registry: Option<Box<Account<'info, SymbolRegistry>>>,
+    #[account(seeds=[WITHHELD_FEE_AUTHORITY_SEED], bump)]
+    /// CHECK: only used as a signing PDA
+    withheld_fee_authority: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = withheld_fee_authority,
+        associated_token::token_program = token_2022_program,
+    )]
+    withheld_fee_vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// CHECK: checked against launch record or symbol registry
+    creator: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_2022_program,
+    )]
+    creator_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+    #[account(address = config.fee_receiver)]
+    /// CHECK: checked by address
+    fee_receiver: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = fee_receiver,
+        associated_token::token_program = token_2022_program,
+    )]
+    fee_receiver_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    // Anyone can harvest, fees are routed to fee receiver and creator
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+    token_2022_program: Program<'info, Token2022>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl HarvestWithheldFeesAccounts<'_> {
+    // Creator recorded in the token's launch record, or in its symbol registry when the token was not launched.
+    // Launch record PDA is always passed, so the creator of a launched token can not be replaced.
+    fn creator_of_token(&self) -> Result<Pubkey> {
+        let launch_record_info = self.launch_record.to_account_info();
+        if !launch_record_info.data_is_empty() {
+            require_keys_eq!(*launch_record_info.owner, crate::ID, CustomError::LaunchRecordMismatch);
+            return Ok(LaunchRecord::try_deserialize(&mut &launch_record_info.try_borrow_data()?[..])?.creator);
+        }
+        let symbol_registry = self.symbol_registry.as_ref().ok_or(CustomError::MissingCreatorAccounts)?;
+        require_keys_eq!(symbol_registry.token_mint, self.token_mint.key(), CustomError::InvalidSymbolRegistry);
+        Ok(symbol_registry.creator)
+    }
+}
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFeesAccounts<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+    token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    #[account(
+        mut,
+        seeds = [CREATOR_ESCROW_SEED, token_mint.key().as_ref()],
+        bump = creator_escrow.bump,
+        has_one = creator @ CustomError::Unauthorized,
+    )]
+    creator_escrow: Box<Account<'info, CreatorEscrow>>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = creator_escrow,
+        associated_token::token_program = token_program,
+    )]
+    creator_escrow_token_account: Option<Box<InterfaceAccount<'info, token_interface::TokenAccount>>>,  // Absent when escrow only received SOL
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    creator_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    system_program: Program<'info, System>,
+    token_program: Interface<'info, TokenInterface>,  // Token program owning token_mint
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
 #[error_code]
 pub enum CustomError {
-    #[msg("At least 95% of token supply must be provided to liquidity pool")]
-    InsufficientLiquidityProvided
+    #[msg("Insufficient share of token supply provided to liquidity pool")]
+    InsufficientLiquidityProvided,
+    #[msg("Signer is not authorized to perform this action")]
+    Unauthorized,
+    #[msg("Invalid config value")]
+    InvalidConfig,
+    #[msg("Invalid price")]
+    InvalidPrice,
+    #[msg("Invalid tick range")]
+    InvalidTickRange,
+    #[msg("Tick index is not a multiple of pool tick spacing")]
+    TickNotAlignedToSpacing,
+    #[msg("Invalid Raydium AMM config account")]
+    InvalidAmmConfig,
+    #[msg("Accounts do not match launch record")]
+    LaunchRecordMismatch,
+    #[msg("Creator accounts are required to pay creator's share of fees")]
+    MissingCreatorAccounts,
+    #[msg("Invalid accounts for harvested position")]
+    InvalidPositionAccounts,
+    #[msg("Transfer fee exceeds maximum allowed")]
+    TransferFeeTooHigh,
+    #[msg("Token decimals are out of allowed range")]
+    InvalidDecimals,
+    #[msg("Token supply is below configured minimum")]
+    SupplyTooLow,
+    #[msg("Token supply is above configured maximum")]
+    SupplyTooHigh,
+    #[msg("Token name is empty")]
+    EmptyName,
+    #[msg("Token name is too long")]
+    NameTooLong,
+    #[msg("Token name may only contain printable ASCII characters")]
+    InvalidNameCharacter,
+    #[msg("Token symbol is empty")]
+    EmptySymbol,
+    #[msg("Token symbol is too long")]
+    SymbolTooLong,
+    #[msg("Token symbol may only contain ASCII letters and digits")]
+    InvalidSymbolCharacter,
+    #[msg("Token URI is too long")]
+    UriTooLong,
+    #[msg("Token URI must start with https://, ipfs:// or ar://")]
+    InvalidUriScheme,
+    #[msg("Token URI may only contain printable ASCII characters without spaces")]
+    InvalidUriCharacter,
+    #[msg("Symbol registry account does not match normalized symbol")]
+    InvalidSymbolRegistry,
+    #[msg("Symbol is reserved")]
+    SymbolReserved,
+    #[msg("Symbol is already taken by another token")]
+    SymbolTaken,
+    #[msg("Symbol is not reserved")]
+    SymbolNotReserved,
+    #[msg("Observation account must be created for Raydium CLMM program with ObservationState size")]
+    InvalidObservationAccount,
+    #[msg("Token mint authority must be revoked")]
+    MintAuthorityNotRevoked,
+    #[msg("Token freeze authority must be revoked")]
+    FreezeAuthorityNotRevoked,
+    #[msg("Token metadata account does not exist for the mint")]
+    MissingTokenMetadata,
+    #[msg("Token metadata must be immutable")]
+    MutableTokenMetadata,
+    #[msg("Entire token supply must be held by the payer")]
+    SupplyNotHeldByPayer,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("WSOL mint must be the native mint")]
+    InvalidWsolMint,
+    #[msg("Memo program must be SPL Memo")]
+    InvalidMemoProgram,
+    #[msg("Pool account does not match Raydium pool of the AMM config and mints")]
+    InvalidPool,
+    #[msg("Vault account does not match Raydium pool vault of the mint")]
+    InvalidPoolVault,
+    #[msg("Account does not match Raydium tick array bitmap extension of the pool")]
+    InvalidTickArrayBitmapExtension,
+    #[msg("Account does not match Raydium protocol position of the tick range")]
+    InvalidProtocolPosition,
+    #[msg("Account does not match Raydium personal position of the position NFT")]
+    InvalidPersonalPosition,
+    #[msg("Account does not match Raydium tick array of the tick range")]
+    InvalidTickArray,
+    #[msg("Position NFT must be held by position NFT owner PDA's associated token account")]
+    PositionNftNotHeldByProgram,
+    #[msg("Liquidity of opened position does not match requested liquidity")]
+    PositionLiquidityMismatch,
+    #[msg("Token symbol must be registered in symbol registry")]
+    SymbolNotRegistered,
+    #[msg("CLMM program does not match launcher config")]
+    InvalidClmmProgram,
+    #[msg("Fee receiver does not match launcher config")]
+    InvalidFeeReceiver,
 }
\ No newline at end of file
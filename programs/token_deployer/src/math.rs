@@ -0,0 +1,350 @@
+use anchor_lang::prelude::*;
+
+use crate::CustomError;
+
+#[allow(clippy::all)]
+mod big_num {
+    use uint::construct_uint;
+    construct_uint! {
+        pub struct U256(4);
+    }
+}
+pub use big_num::U256;
+
+pub const Q64: u128 = 1 << 64;
+
+// Inverts Q64.64 square root of price: floor(2^128 / sqrt_price_x64)
+pub fn invert_sqrt_price_x64(sqrt_price_x64: u128) -> Result<u128> {
+    require!(sqrt_price_x64 > 1, CustomError::InvalidPrice);
+    // 2^128 does not fit u128, so divide 2^128 - 1 and correct when sqrt_price_x64 divides 2^128 exactly:
+    let quotient = u128::MAX / sqrt_price_x64;
+    if u128::MAX % sqrt_price_x64 == sqrt_price_x64 - 1 {
+        Ok(quotient + 1)
+    } else {
+        Ok(quotient)
+    }
+}
+
+// Inverts tick range [tick_lower, tick_upper] to [-tick_upper, -tick_lower]
+pub fn invert_tick_range(tick_lower_index: i32, tick_upper_index: i32) -> Result<(i32, i32)> {
+    let lower = tick_upper_index.checked_neg().ok_or(CustomError::InvalidTickRange)?;
+    let upper = tick_lower_index.checked_neg().ok_or(CustomError::InvalidTickRange)?;
+    Ok((lower, upper))
+}
+
+// Raydium CLMM tick and price bounds and number of ticks stored in a single tick array account
+pub const MIN_TICK: i32 = -443636;
+pub const MAX_TICK: i32 = 443636;
+pub const MIN_SQRT_PRICE_X64: u128 = 4295048016;
+pub const MAX_SQRT_PRICE_X64: u128 = 79226673521066979257578248091;
+pub const TICK_ARRAY_SIZE: i32 = 60;
+
+// Checks that tick range is within bounds, non-empty and aligned to tick spacing
+pub fn check_tick_range(tick_lower_index: i32, tick_upper_index: i32, tick_spacing: u16) -> Result<()> {
+    require!(tick_lower_index < tick_upper_index, CustomError::InvalidTickRange);
+    require!(tick_lower_index >= MIN_TICK && tick_upper_index <= MAX_TICK, CustomError::InvalidTickRange);
+    require!(
+        tick_lower_index % tick_spacing as i32 == 0 && tick_upper_index % tick_spacing as i32 == 0,
+        CustomError::TickNotAlignedToSpacing
+    );
+    Ok(())
+}
+
+// Start index of the tick array containing `tick_index`
+pub fn tick_array_start_index(tick_index: i32, tick_spacing: u16) -> i32 {
+    let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+    tick_index.div_euclid(ticks_in_array) * ticks_in_array
+}
+
+// Greatest multiple of tick spacing that is not above `tick_index`
+pub fn round_tick_down(tick_index: i32, tick_spacing: u16) -> i32 {
+    tick_index.div_euclid(tick_spacing as i32) * tick_spacing as i32
+}
+
+// Q64.64 square root of 1.0001^tick, same as Raydium CLMM tick_math::get_sqrt_price_at_tick
+pub fn get_sqrt_price_at_tick(tick: i32) -> Result<u128> {
+    let abs_tick = tick.unsigned_abs();
+    require!(abs_tick <= MAX_TICK as u32, CustomError::InvalidTickRange);
+
+    // Each factor is 2^64 / 1.0001^(2^(i - 1)) for bit i of |tick|
+    const FACTORS: [u128; 18] = [
+        0xfff97272373d4000,
+        0xfff2e50f5f657000,
+        0xffe5caca7e10f000,
+        0xffcb9843d60f7000,
+        0xff973b41fa98e800,
+        0xff2ea16466c9b000,
+        0xfe5dee046a9a3800,
+        0xfcbe86c7900bb000,
+        0xf987a7253ac65800,
+        0xf3392b0822bb6000,
+        0xe7159475a2caf000,
+        0xd097f3bdfd2f2000,
+        0xa9f746462d9f8000,
+        0x70d869a156f31c00,
+        0x31be135f97ed3200,
+        0x9aa508b5b85a500,
+        0x5d6af8dedc582c,
+        0x2216e584f5fa,
+    ];
+    let mut ratio: u128 = if abs_tick & 0x1 != 0 { 0xfffcb933bd6fb800 } else { Q64 };
+    for (i, factor) in FACTORS.iter().enumerate() {
+        if abs_tick & (0x2 << i) != 0 {
+            ratio = (ratio * factor) >> 64;
+        }
+    }
+    // Factors give 1 / sqrt(1.0001)^|tick|, invert for positive ticks:
+    if tick > 0 {
+        ratio = u128::MAX / ratio;
+    }
+    Ok(ratio)
+}
+
+// Greatest tick such that get_sqrt_price_at_tick(tick) <= sqrt_price_x64,
+// same as Raydium CLMM tick_math::get_tick_at_sqrt_price
+pub fn get_tick_at_sqrt_price(sqrt_price_x64: u128) -> Result<i32> {
+    require!(
+        (MIN_SQRT_PRICE_X64..MAX_SQRT_PRICE_X64).contains(&sqrt_price_x64),
+        CustomError::InvalidPrice
+    );
+
+    // Integer part of log2(sqrt_price):
+    let msb: u32 = 128 - sqrt_price_x64.leading_zeros() - 1;
+    let log2p_integer_x32 = (msb as i128 - 64) << 32;
+
+    // Fractional part of log2(sqrt_price), 16 bits of precision:
+    let mut bit: i128 = 0x8000_0000_0000_0000i128;
+    let mut precision = 0;
+    let mut log2p_fraction_x64 = 0;
+    let mut r = if msb >= 64 {
+        sqrt_price_x64 >> (msb - 63)
+    } else {
+        sqrt_price_x64 << (63 - msb)
+    };
+    while bit > 0 && precision < 16 {
+        r *= r;
+        let is_r_more_than_two = r >> 127_u32;
+        r >>= 63 + is_r_more_than_two;
+        log2p_fraction_x64 += bit * is_r_more_than_two as i128;
+        bit >>= 1;
+        precision += 1;
+    }
+    let log2p_fraction_x32 = log2p_fraction_x64 >> 32;
+    let log2p_x32 = log2p_integer_x32 + log2p_fraction_x32;
+
+    // Change of base: multiply by 2^16 / log2(sqrt(1.0001))
+    let log_sqrt_10001_x64 = log2p_x32 * 59543866431248i128;
+    let tick_low = ((log_sqrt_10001_x64 - 184467440737095516i128) >> 64) as i32;
+    let tick_high = ((log_sqrt_10001_x64 + 15793534762490258745i128) >> 64) as i32;
+
+    Ok(if tick_low == tick_high || get_sqrt_price_at_tick(tick_high)? > sqrt_price_x64 {
+        tick_low
+    } else {
+        tick_high
+    })
+}
+
+// Q64.64 square root of price (lamports per smallest token unit) at the given fully diluted valuation.
+// Both amounts are raw, so token and WSOL (9) decimals are accounted for by construction.
+pub fn sqrt_price_x64_from_fdv(fdv_lamports: u64, supply: u64) -> Result<u128> {
+    require!(fdv_lamports > 0 && supply > 0, CustomError::InvalidPrice);
+    let price_x128 = (U256::from(fdv_lamports) << 128) / U256::from(supply);
+    let sqrt_price_x64 = price_x128.integer_sqrt();
+    // sqrt(u64::MAX * 2^128) < 2^96
+    Ok(sqrt_price_x64.as_u128())
+}
+
+// Liquidity provided by `amount_0` of token A in [sqrt_price_a_x64, sqrt_price_b_x64] range, rounded down:
+// L = amount_0 * sqrt_price_a * sqrt_price_b / (sqrt_price_b - sqrt_price_a)
+pub fn get_liquidity_from_amount_0(sqrt_price_a_x64: u128, sqrt_price_b_x64: u128, amount_0: u64) -> Result<u128> {
+    require!(sqrt_price_a_x64 < sqrt_price_b_x64, CustomError::InvalidTickRange);
+    let intermediate = U256::from(sqrt_price_a_x64) * U256::from(sqrt_price_b_x64) / U256::from(Q64);
+    let liquidity = U256::from(amount_0) * intermediate / U256::from(sqrt_price_b_x64 - sqrt_price_a_x64);
+    require!(liquidity.bits() <= 128, CustomError::InvalidPrice);
+    Ok(liquidity.as_u128())
+}
+
+// Liquidity provided by `amount_1` of token B in [sqrt_price_a_x64, sqrt_price_b_x64] range, rounded down:
+// L = amount_1 / (sqrt_price_b - sqrt_price_a)
+pub fn get_liquidity_from_amount_1(sqrt_price_a_x64: u128, sqrt_price_b_x64: u128, amount_1: u64) -> Result<u128> {
+    require!(sqrt_price_a_x64 < sqrt_price_b_x64, CustomError::InvalidTickRange);
+    let liquidity = U256::from(amount_1) * U256::from(Q64) / U256::from(sqrt_price_b_x64 - sqrt_price_a_x64);
+    require!(liquidity.bits() <= 128, CustomError::InvalidPrice);
+    Ok(liquidity.as_u128())
+}
+
+// Initial price, tick range and liquidity of a position, in pool orientation (price of side A in side B)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolPosition {
+    pub sqrt_price_x64: u128,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+}
+
+// Single-sided token position spanning [initial_fdv_lamports, upper_fdv_lamports] market caps.
+// Ticks are rounded down to tick spacing and pool price is set to the range edge at initial market cap,
+// so the position is entirely made of `token_amount` tokens and no WSOL is required.
+pub fn position_from_market_cap(
+    token_is_mint_a: bool,
+    initial_fdv_lamports: u64,
+    upper_fdv_lamports: u64,
+    supply: u64,
+    token_amount: u64,
+    tick_spacing: u16,
+) -> Result<PoolPosition> {
+    require!(initial_fdv_lamports < upper_fdv_lamports, CustomError::InvalidPrice);
+    // Tick range in SOL per token:
+    let tick_lower_index = round_tick_down(get_tick_at_sqrt_price(sqrt_price_x64_from_fdv(initial_fdv_lamports, supply)?)?, tick_spacing);
+    let tick_upper_index = round_tick_down(get_tick_at_sqrt_price(sqrt_price_x64_from_fdv(upper_fdv_lamports, supply)?)?, tick_spacing);
+    let position = if token_is_mint_a {
+        check_tick_range(tick_lower_index, tick_upper_index, tick_spacing)?;
+        let sqrt_price_lower_x64 = get_sqrt_price_at_tick(tick_lower_index)?;
+        let sqrt_price_upper_x64 = get_sqrt_price_at_tick(tick_upper_index)?;
+        PoolPosition {
+            sqrt_price_x64: sqrt_price_lower_x64,
+            tick_lower_index,
+            tick_upper_index,
+            liquidity: get_liquidity_from_amount_0(sqrt_price_lower_x64, sqrt_price_upper_x64, token_amount)?,
+        }
+    } else {
+        // Token is side B, so pool price is WSOL per token and initial market cap is the upper range edge:
+        let (tick_lower_index, tick_upper_index) = invert_tick_range(tick_lower_index, tick_upper_index)?;
+        check_tick_range(tick_lower_index, tick_upper_index, tick_spacing)?;
+        let sqrt_price_lower_x64 = get_sqrt_price_at_tick(tick_lower_index)?;
+        let sqrt_price_upper_x64 = get_sqrt_price_at_tick(tick_upper_index)?;
+        PoolPosition {
+            sqrt_price_x64: sqrt_price_upper_x64,
+            tick_lower_index,
+            tick_upper_index,
+            liquidity: get_liquidity_from_amount_1(sqrt_price_lower_x64, sqrt_price_upper_x64, token_amount)?,
+        }
+    };
+    require!(position.liquidity > 0, CustomError::InsufficientLiquidityProvided);
+    Ok(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Token amounts Raydium requires for a given liquidity, rounded up (sqrt_price_math::get_delta_amount_*_unsigned)
+    fn amount_0_for_liquidity(sqrt_price_a_x64: u128, sqrt_price_b_x64: u128, liquidity: u128) -> u128 {
+        let numerator = (U256::from(liquidity) << 64) * U256::from(sqrt_price_b_x64 - sqrt_price_a_x64);
+        let ceil_div = |a: U256, b: U256| (a + b - 1) / b;
+        ceil_div(ceil_div(numerator, U256::from(sqrt_price_b_x64)), U256::from(sqrt_price_a_x64)).as_u128()
+    }
+
+    fn amount_1_for_liquidity(sqrt_price_a_x64: u128, sqrt_price_b_x64: u128, liquidity: u128) -> u128 {
+        let numerator = U256::from(liquidity) * U256::from(sqrt_price_b_x64 - sqrt_price_a_x64);
+        ((numerator + U256::from(Q64) - 1) / U256::from(Q64)).as_u128()
+    }
+
+    #[test]
+    fn sqrt_price_at_tick_matches_raydium_bounds() {
+        assert_eq!(get_sqrt_price_at_tick(MIN_TICK).unwrap(), MIN_SQRT_PRICE_X64);
+        assert_eq!(get_sqrt_price_at_tick(MAX_TICK).unwrap(), MAX_SQRT_PRICE_X64);
+        assert_eq!(get_sqrt_price_at_tick(0).unwrap(), Q64);
+        assert!(get_sqrt_price_at_tick(MAX_TICK + 1).is_err());
+    }
+
+    #[test]
+    fn tick_at_sqrt_price_matches_raydium_bounds() {
+        assert_eq!(get_tick_at_sqrt_price(MIN_SQRT_PRICE_X64).unwrap(), MIN_TICK);
+        assert_eq!(get_tick_at_sqrt_price(MAX_SQRT_PRICE_X64 - 1).unwrap(), MAX_TICK - 1);
+        assert_eq!(get_tick_at_sqrt_price(Q64).unwrap(), 0);
+        assert!(get_tick_at_sqrt_price(MIN_SQRT_PRICE_X64 - 1).is_err());
+        assert!(get_tick_at_sqrt_price(MAX_SQRT_PRICE_X64).is_err());
+    }
+
+    #[test]
+    fn tick_at_sqrt_price_rounds_down() {
+        for tick in [-28861, 28861] {
+            let sqrt_price_x64 = get_sqrt_price_at_tick(tick).unwrap();
+            assert_eq!(get_tick_at_sqrt_price(sqrt_price_x64).unwrap(), tick);
+            assert_eq!(get_tick_at_sqrt_price(sqrt_price_x64 + 1).unwrap(), tick);
+            assert_eq!(get_tick_at_sqrt_price(sqrt_price_x64 - 1).unwrap(), tick - 1);
+            assert_eq!(get_tick_at_sqrt_price(get_sqrt_price_at_tick(tick + 1).unwrap() - 1).unwrap(), tick);
+        }
+    }
+
+    #[test]
+    fn inverts_sqrt_price() {
+        assert_eq!(invert_sqrt_price_x64(Q64).unwrap(), Q64);
+        assert_eq!(invert_sqrt_price_x64(2 * Q64).unwrap(), Q64 / 2);
+        assert_eq!(invert_sqrt_price_x64(3).unwrap(), u128::MAX / 3);
+        assert!(invert_sqrt_price_x64(1).is_err());
+    }
+
+    #[test]
+    fn tick_array_start_index_rounds_towards_negative_infinity() {
+        assert_eq!(tick_array_start_index(0, 10), 0);
+        assert_eq!(tick_array_start_index(599, 10), 0);
+        assert_eq!(tick_array_start_index(600, 10), 600);
+        assert_eq!(tick_array_start_index(-1, 10), -600);
+        assert_eq!(tick_array_start_index(-600, 10), -600);
+        assert_eq!(tick_array_start_index(-601, 10), -1200);
+    }
+
+    #[test]
+    fn sqrt_price_from_fdv_uses_raw_amounts() {
+        // FDV equal to raw supply is 1 lamport per smallest token unit:
+        assert_eq!(sqrt_price_x64_from_fdv(1_000_000, 1_000_000).unwrap(), Q64);
+        // 1B tokens with 6 decimals at 30 SOL FDV: 3e-8 SOL per token, 3e-5 lamports per smallest unit
+        let sqrt_price_x64 = sqrt_price_x64_from_fdv(30_000_000_000, 1_000_000_000_000_000).unwrap();
+        let raw_price = (sqrt_price_x64 as f64 / Q64 as f64).powi(2);
+        assert!((raw_price / 3e-5 - 1.0).abs() < 1e-9);
+        let sol_per_token = raw_price * 1e6 / 1e9;
+        assert!((sol_per_token / 3e-8 - 1.0).abs() < 1e-9);
+        assert_eq!(get_tick_at_sqrt_price(sqrt_price_x64).unwrap(), -104149);
+    }
+
+    #[test]
+    fn position_from_market_cap_when_token_is_side_a() {
+        let token_amount = 950_000_000_000_000;
+        let position = position_from_market_cap(
+            true,
+            30_000_000_000,
+            3_000_000_000_000,
+            1_000_000_000_000_000,
+            token_amount,
+            120,
+        ).unwrap();
+        assert_eq!(position.tick_lower_index, -104160);
+        assert_eq!(position.tick_upper_index, -58200);
+        let sqrt_price_lower_x64 = get_sqrt_price_at_tick(position.tick_lower_index).unwrap();
+        let sqrt_price_upper_x64 = get_sqrt_price_at_tick(position.tick_upper_index).unwrap();
+        assert_eq!(position.sqrt_price_x64, sqrt_price_lower_x64);
+        let amount = amount_0_for_liquidity(sqrt_price_lower_x64, sqrt_price_upper_x64, position.liquidity);
+        assert!(amount <= token_amount as u128);
+        assert!(amount + 1_000 >= token_amount as u128);
+    }
+
+    #[test]
+    fn position_from_market_cap_when_token_is_side_b() {
+        let token_amount = 950_000_000_000_000;
+        let position = position_from_market_cap(
+            false,
+            30_000_000_000,
+            3_000_000_000_000,
+            1_000_000_000_000_000,
+            token_amount,
+            120,
+        ).unwrap();
+        assert_eq!(position.tick_lower_index, 58200);
+        assert_eq!(position.tick_upper_index, 104160);
+        let sqrt_price_lower_x64 = get_sqrt_price_at_tick(position.tick_lower_index).unwrap();
+        let sqrt_price_upper_x64 = get_sqrt_price_at_tick(position.tick_upper_index).unwrap();
+        assert_eq!(position.sqrt_price_x64, sqrt_price_upper_x64);
+        let amount = amount_1_for_liquidity(sqrt_price_lower_x64, sqrt_price_upper_x64, position.liquidity);
+        assert!(amount <= token_amount as u128);
+        assert!(amount + 1_000 >= token_amount as u128);
+    }
+
+    #[test]
+    fn position_from_market_cap_rejects_empty_range() {
+        // Both market caps round down to the same tick:
+        assert!(position_from_market_cap(true, 30_000_000_000, 30_000_000_001, 1_000_000_000_000_000, 1, 120).is_err());
+        assert!(position_from_market_cap(true, 30_000_000_000, 30_000_000_000, 1_000_000_000_000_000, 1, 120).is_err());
+    }
+}
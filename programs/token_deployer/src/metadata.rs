@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::CustomError;
+
+// Metaplex limits, also applied to Token-2022 metadata:
+pub const MAX_NAME_LENGTH: usize = 32;
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+pub const MAX_URI_LENGTH: usize = 200;
+pub const ALLOWED_URI_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+// Token metadata validated and normalized before it is written on-chain
+pub struct TokenMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+pub fn normalize_metadata(name: &str, symbol: &str, uri: &str) -> Result<TokenMetadataArgs> {
+    Ok(TokenMetadataArgs {
+        name: normalize_name(name)?,
+        symbol: normalize_symbol(symbol)?,
+        uri: validate_uri(uri)?,
+    })
+}
+
+// Name is trimmed and may contain printable ASCII characters and spaces
+pub fn normalize_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    require!(!name.is_empty(), CustomError::EmptyName);
+    require!(name.len() <= MAX_NAME_LENGTH, CustomError::NameTooLong);
+    require!(name.chars().all(|c| c.is_ascii_graphic() || c == ' '), CustomError::InvalidNameCharacter);
+    Ok(name.to_string())
+}
+
+// Symbol is trimmed, uppercased and may only contain ASCII letters and digits
+pub fn normalize_symbol(symbol: &str) -> Result<String> {
+    let symbol = symbol.trim();
+    require!(!symbol.is_empty(), CustomError::EmptySymbol);
+    require!(symbol.len() <= MAX_SYMBOL_LENGTH, CustomError::SymbolTooLong);
+    require!(symbol.chars().all(|c| c.is_ascii_alphanumeric()), CustomError::InvalidSymbolCharacter);
+    Ok(symbol.to_ascii_uppercase())
+}
+
+// URI must use one of allowed schemes and may not contain whitespace or non-ASCII characters
+pub fn validate_uri(uri: &str) -> Result<String> {
+    require!(uri.len() <= MAX_URI_LENGTH, CustomError::UriTooLong);
+    require!(uri.chars().all(|c| c.is_ascii_graphic()), CustomError::InvalidUriCharacter);
+    let has_allowed_scheme = ALLOWED_URI_SCHEMES
+        .iter()
+        .any(|scheme| uri.len() > scheme.len() && uri.starts_with(scheme));
+    require!(has_allowed_scheme, CustomError::InvalidUriScheme);
+    Ok(uri.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_code(result: Result<String>) -> u32 {
+        match result.unwrap_err() {
+            Error::AnchorError(e) => e.error_code_number,
+            e => panic!("unexpected error {e:?}"),
+        }
+    }
+
+    fn code(error: CustomError) -> u32 {
+        anchor_lang::error::ERROR_CODE_OFFSET + error as u32
+    }
+
+    #[test]
+    fn normalizes_symbol() {
+        assert_eq!(normalize_symbol("  rkt1 ").unwrap(), "RKT1");
+        assert_eq!(error_code(normalize_symbol("   ")), code(CustomError::EmptySymbol));
+        assert_eq!(error_code(normalize_symbol("ROCKETROCKET")), code(CustomError::SymbolTooLong));
+        assert_eq!(error_code(normalize_symbol("R-KT")), code(CustomError::InvalidSymbolCharacter));
+        assert_eq!(error_code(normalize_symbol("RКТ")), code(CustomError::InvalidSymbolCharacter));
+    }
+
+    #[test]
+    fn normalizes_name() {
+        assert_eq!(normalize_name(" Rocket Token ").unwrap(), "Rocket Token");
+        assert_eq!(error_code(normalize_name("")), code(CustomError::EmptyName));
+        assert_eq!(error_code(normalize_name(&"a".repeat(MAX_NAME_LENGTH + 1))), code(CustomError::NameTooLong));
+        assert_eq!(error_code(normalize_name("Rocket\nToken")), code(CustomError::InvalidNameCharacter));
+    }
+
+    #[test]
+    fn validates_uri() {
+        for uri in ["https://example.com/token.json", "ipfs://bafybeigdyrzt", "ar://Zg3ZpG"] {
+            assert_eq!(validate_uri(uri).unwrap(), uri);
+        }
+        assert_eq!(error_code(validate_uri("http://example.com")), code(CustomError::InvalidUriScheme));
+        assert_eq!(error_code(validate_uri("https://")), code(CustomError::InvalidUriScheme));
+        assert_eq!(error_code(validate_uri("")), code(CustomError::InvalidUriScheme));
+        assert_eq!(error_code(validate_uri("https://example.com/a b")), code(CustomError::InvalidUriCharacter));
+        let long_uri = format!("https://{}", "a".repeat(MAX_URI_LENGTH));
+        assert_eq!(error_code(validate_uri(&long_uri)), code(CustomError::UriTooLong));
+    }
+}
@@ -1,35 +1,32 @@
 use anchor_lang::prelude::*;
 
-const CREATE_POOL_DISCRIMINATOR: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
+use super::{CreatePoolArgs, RaydiumInstruction};
 
 
 pub fn create_pool<'info>(
     ctx: CpiContext<'_, '_, '_, 'info, CreatePool<'info>>,
-    sqrt_price_x64: u128,
+    args: CreatePoolArgs,
 ) -> Result<()> {
     // 1. Build createPool instruction:
     // 1.1. Build accounts:
-    let mut accounts = Vec::with_capacity(13);
-    accounts.push(AccountMeta::new(ctx.accounts.pool_creator.key(), true));  // 1
-    accounts.push(AccountMeta::new_readonly(ctx.accounts.amm_config_id.key(), false));  // 2
-    accounts.push(AccountMeta::new(ctx.accounts.pool_id.key(), false));  // 3
-    accounts.push(AccountMeta::new_readonly(ctx.accounts.mint_a.key(), false));  // 4
-    accounts.push(AccountMeta::new_readonly(ctx.accounts.mint_b.key(), false));  // 5
-    accounts.push(AccountMeta::new(ctx.accounts.mint_vault_a.key(), false));  // 6
-    accounts.push(AccountMeta::new(ctx.accounts.mint_vault_b.key(), false));  // 7
-    accounts.push(AccountMeta::new(ctx.accounts.observation_id.key(), false));  // 8
-    accounts.push(AccountMeta::new(ctx.accounts.ex_tick_array_bitmap.key(), false));  // 9
-    accounts.push(AccountMeta::new_readonly(ctx.accounts.mint_program_id_a.key(), false));  // 10
-    accounts.push(AccountMeta::new_readonly(ctx.accounts.mint_program_id_b.key(), false));  // 11
-    accounts.push(AccountMeta::new_readonly(ctx.accounts.system_program.key(), false));  // 12
-    accounts.push(AccountMeta::new_readonly(ctx.accounts.rent_program.key(), false));  // 13
+    let accounts = vec![
+        AccountMeta::new(ctx.accounts.pool_creator.key(), true),  // 1
+        AccountMeta::new_readonly(ctx.accounts.amm_config_id.key(), false),  // 2
+        AccountMeta::new(ctx.accounts.pool_id.key(), false),  // 3
+        AccountMeta::new_readonly(ctx.accounts.mint_a.key(), false),  // 4
+        AccountMeta::new_readonly(ctx.accounts.mint_b.key(), false),  // 5
+        AccountMeta::new(ctx.accounts.mint_vault_a.key(), false),  // 6
+        AccountMeta::new(ctx.accounts.mint_vault_b.key(), false),  // 7
+        AccountMeta::new(ctx.accounts.observation_id.key(), false),  // 8
+        AccountMeta::new(ctx.accounts.ex_tick_array_bitmap.key(), false),  // 9
+        AccountMeta::new_readonly(ctx.accounts.mint_program_id_a.key(), false),  // 10
+        AccountMeta::new_readonly(ctx.accounts.mint_program_id_b.key(), false),  // 11
+        AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),  // 12
+        AccountMeta::new_readonly(ctx.accounts.rent_program.key(), false),  // 13
+    ];
 
     // 1.2. Build data:
-    let mut data: Vec<u8> = Vec::with_capacity(32);
-    let start_time: u64 = 0;
-    data.extend_from_slice(&CREATE_POOL_DISCRIMINATOR);
-    data.extend_from_slice(&sqrt_price_x64.to_le_bytes());
-    data.extend_from_slice(&start_time.to_le_bytes());
+    let data = args.data()?;
 
     // 1.3. Build instruction:
     let ix = anchor_lang::solana_program::instruction::Instruction {
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-const DECREASE_LIQUIDITY_V2_DISCRIMINATOR: [u8; 8] = [58, 127, 188, 62, 79, 82, 196, 96];
+use super::{DecreaseLiquidityV2Args, RaydiumInstruction};
 
 
 pub fn harvest<'info>(
@@ -49,9 +49,8 @@ pub fn harvest<'info>(
     );
 
     // 1.2. Build data:
-    let mut data: Vec<u8> = Vec::with_capacity(40);
-    data.extend_from_slice(&DECREASE_LIQUIDITY_V2_DISCRIMINATOR);
-    data.resize(40, 0);  // adding 32 zero-bytes repr struct {liquidity: u128 = 0, amount_0_max: u64 = 0, amount_1_max: u64 = 0}
+    // Decreasing zero liquidity only collects fees of the position
+    let data = DecreaseLiquidityV2Args::default().data()?;
 
     // 1.3. Build instruction:
     let ix = anchor_lang::solana_program::instruction::Instruction {
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+// Anchor instruction discriminator: first 8 bytes of sha256("global:<name>")
+pub fn sighash(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{name}").as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+// Arguments of Raydium CLMM instruction, encoded as its discriminator followed by Borsh-serialized fields
+pub trait RaydiumInstruction: AnchorSerialize {
+    const NAME: &'static str;
+
+    fn data(&self) -> Result<Vec<u8>> {
+        let mut data = sighash(Self::NAME).to_vec();
+        self.serialize(&mut data).map_err(|_| ErrorCode::InstructionDidNotSerialize)?;
+        Ok(data)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct CreatePoolArgs {
+    pub sqrt_price_x64: u128,
+    pub open_time: u64,
+}
+
+impl RaydiumInstruction for CreatePoolArgs {
+    const NAME: &'static str = "create_pool";
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct OpenPositionV2Args {
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub tick_array_lower_start_index: i32,
+    pub tick_array_upper_start_index: i32,
+    pub liquidity: u128,
+    pub amount_0_max: u64,
+    pub amount_1_max: u64,
+    pub with_metadata: bool,
+    pub base_flag: Option<bool>,
+}
+
+impl RaydiumInstruction for OpenPositionV2Args {
+    const NAME: &'static str = "open_position_v2";
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct DecreaseLiquidityV2Args {
+    pub liquidity: u128,
+    pub amount_0_min: u64,
+    pub amount_1_min: u64,
+}
+
+impl RaydiumInstruction for DecreaseLiquidityV2Args {
+    const NAME: &'static str = "decrease_liquidity_v2";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Discriminators of Raydium CLMM IDL (raydium-io/raydium-clmm, programs/amm)
+    #[test]
+    fn discriminators_match_idl() {
+        assert_eq!(sighash(CreatePoolArgs::NAME), [233, 146, 209, 142, 207, 104, 64, 188]);
+        assert_eq!(sighash(OpenPositionV2Args::NAME), [77, 184, 74, 214, 112, 86, 241, 199]);
+        assert_eq!(sighash(DecreaseLiquidityV2Args::NAME), [58, 127, 188, 62, 79, 82, 196, 96]);
+    }
+
+    #[test]
+    fn encodes_create_pool() {
+        let args = CreatePoolArgs { sqrt_price_x64: 1 << 64, open_time: 0x0102 };
+        let expected: Vec<u8> = [
+            &[233, 146, 209, 142, 207, 104, 64, 188][..],
+            &[0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],  // sqrt_price_x64: u128
+            &[2, 1, 0, 0, 0, 0, 0, 0],  // open_time: u64
+        ].concat();
+        assert_eq!(args.data().unwrap(), expected);
+    }
+
+    #[test]
+    fn encodes_open_position_v2() {
+        let args = OpenPositionV2Args {
+            tick_lower_index: -120,
+            tick_upper_index: 600,
+            tick_array_lower_start_index: -3600,
+            tick_array_upper_start_index: 0,
+            liquidity: 0x0102_0304,
+            amount_0_max: 5,
+            amount_1_max: u64::MAX,
+            with_metadata: true,
+            base_flag: None,
+        };
+        let expected: Vec<u8> = [
+            &[77, 184, 74, 214, 112, 86, 241, 199][..],
+            &[0x88, 0xff, 0xff, 0xff],  // tick_lower_index: i32
+            &[0x58, 0x02, 0, 0],  // tick_upper_index: i32
+            &[0xf0, 0xf1, 0xff, 0xff],  // tick_array_lower_start_index: i32
+            &[0, 0, 0, 0],  // tick_array_upper_start_index: i32
+            &[4, 3, 2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],  // liquidity: u128
+            &[5, 0, 0, 0, 0, 0, 0, 0],  // amount_0_max: u64
+            &[0xff; 8],  // amount_1_max: u64
+            &[1],  // with_metadata: bool
+            &[0],  // base_flag: Option<bool>
+        ].concat();
+        assert_eq!(args.data().unwrap(), expected);
+        let args = OpenPositionV2Args { base_flag: Some(false), ..args };
+        assert_eq!(args.data().unwrap()[expected.len() - 1..], [1, 0]);
+    }
+
+    #[test]
+    fn encodes_decrease_liquidity_v2() {
+        let expected: Vec<u8> = [
+            &[58, 127, 188, 62, 79, 82, 196, 96][..],
+            &[0; 16],  // liquidity: u128
+            &[0; 8],  // amount_0_min: u64
+            &[0; 8],  // amount_1_min: u64
+        ].concat();
+        assert_eq!(DecreaseLiquidityV2Args::default().data().unwrap(), expected);
+    }
+}
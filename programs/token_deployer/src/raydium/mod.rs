@@ -1,3 +1,6 @@
+pub mod instruction;
+pub use instruction::*;
+
 pub mod create_pool;
 pub use create_pool::*;
 
@@ -6,3 +9,9 @@ pub use open_position::*;
 
 pub mod harvest;
 pub use harvest::*;
+
+pub mod pda;
+pub use pda::*;
+
+pub mod state;
+pub use state::*;
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+// Raydium CLMM seeds (states/*.rs)
+pub const POOL_SEED: &[u8] = b"pool";
+pub const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+pub const POOL_TICK_ARRAY_BITMAP_SEED: &[u8] = b"pool_tick_array_bitmap_extension";
+pub const POSITION_SEED: &[u8] = b"position";
+pub const TICK_ARRAY_SEED: &[u8] = b"tick_array";
+
+// Pool of AMM config, mints are sorted (mint_a < mint_b)
+pub fn pool_address(clmm_program_id: &Pubkey, amm_config: &Pubkey, mint_a: &Pubkey, mint_b: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POOL_SEED, amm_config.as_ref(), mint_a.as_ref(), mint_b.as_ref()], clmm_program_id).0
+}
+
+pub fn pool_vault_address(clmm_program_id: &Pubkey, pool: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POOL_VAULT_SEED, pool.as_ref(), mint.as_ref()], clmm_program_id).0
+}
+
+pub fn tick_array_bitmap_extension_address(clmm_program_id: &Pubkey, pool: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POOL_TICK_ARRAY_BITMAP_SEED, pool.as_ref()], clmm_program_id).0
+}
+
+// Tick range is in pool orientation
+pub fn protocol_position_address(clmm_program_id: &Pubkey, pool: &Pubkey, tick_lower_index: i32, tick_upper_index: i32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[POSITION_SEED, pool.as_ref(), &tick_lower_index.to_be_bytes(), &tick_upper_index.to_be_bytes()],
+        clmm_program_id,
+    ).0
+}
+
+pub fn personal_position_address(clmm_program_id: &Pubkey, position_nft_mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[POSITION_SEED, position_nft_mint.as_ref()], clmm_program_id).0
+}
+
+pub fn tick_array_address(clmm_program_id: &Pubkey, pool: &Pubkey, start_index: i32) -> Pubkey {
+    Pubkey::find_program_address(&[TICK_ARRAY_SEED, pool.as_ref(), &start_index.to_be_bytes()], clmm_program_id).0
+}
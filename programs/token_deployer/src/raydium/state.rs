@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+use crate::CustomError;
+
+const AMM_CONFIG_DISCRIMINATOR: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
+// AmmConfig layout: discriminator (8), bump (1), index (2), owner (32), protocol_fee_rate (4), trade_fee_rate (4), tick_spacing (2), ...
+const AMM_CONFIG_TICK_SPACING_OFFSET: usize = 51;
+
+const POOL_STATE_DISCRIMINATOR: [u8; 8] = [247, 237, 227, 245, 215, 195, 222, 70];
+// PoolState layout: discriminator (8), bump (1), amm_config (32), owner (32), token_mint_0 (32), token_mint_1 (32),
+// token_vault_0 (32), token_vault_1 (32), observation_key (32), mint_decimals_0 (1), mint_decimals_1 (1), tick_spacing (2), ...
+const POOL_STATE_AMM_CONFIG_OFFSET: usize = 9;
+const POOL_STATE_TICK_SPACING_OFFSET: usize = 235;
+
+const PERSONAL_POSITION_STATE_DISCRIMINATOR: [u8; 8] = [70, 111, 150, 126, 230, 15, 25, 117];
+// PersonalPositionState layout: discriminator (8), bump (1), nft_mint (32), pool_id (32), tick_lower_index (4), tick_upper_index (4),
+// liquidity (16), ...
+const PERSONAL_POSITION_NFT_MINT_OFFSET: usize = 9;
+const PERSONAL_POSITION_POOL_ID_OFFSET: usize = 41;
+const PERSONAL_POSITION_TICK_LOWER_INDEX_OFFSET: usize = 73;
+const PERSONAL_POSITION_LIQUIDITY_OFFSET: usize = 81;
+
+// ObservationState::LEN: discriminator (8), initialized (1), pool_id (32), 1000 observations (52 each), padding (16 * 5)
+pub const OBSERVATION_STATE_LEN: usize = 8 + 1 + 32 + 52 * 1000 + 16 * 5;
+
+
+// Reads tick spacing from Raydium AmmConfig account owned by `clmm_program_id`
+pub fn read_tick_spacing(amm_config: &AccountInfo<'_>, clmm_program_id: &Pubkey) -> Result<u16> {
+    require_keys_eq!(*amm_config.owner, *clmm_program_id, CustomError::InvalidAmmConfig);
+    parse_tick_spacing(&amm_config.try_borrow_data()?)
+}
+
+// Reads tick spacing from AmmConfig account data
+pub fn parse_tick_spacing(data: &[u8]) -> Result<u16> {
+    require!(data.len() >= AMM_CONFIG_TICK_SPACING_OFFSET + 2, CustomError::InvalidAmmConfig);
+    require!(data[..8] == AMM_CONFIG_DISCRIMINATOR, CustomError::InvalidAmmConfig);
+    let tick_spacing = u16::from_le_bytes([data[AMM_CONFIG_TICK_SPACING_OFFSET], data[AMM_CONFIG_TICK_SPACING_OFFSET + 1]]);
+    require!(tick_spacing > 0, CustomError::InvalidAmmConfig);
+    Ok(tick_spacing)
+}
+
+// Checks that observation account was created for `clmm_program_id` with ObservationState size.
+// It is larger than accounts can be allocated in CPI (MAX_PERMITTED_DATA_INCREASE), so it must be
+// created by a top-level instruction earlier in the transaction; Raydium initializes it in create_pool.
+pub fn check_observation_account(observation: &AccountInfo<'_>, clmm_program_id: &Pubkey) -> Result<()> {
+    require_keys_eq!(*observation.owner, *clmm_program_id, CustomError::InvalidObservationAccount);
+    require_eq!(observation.data_len(), OBSERVATION_STATE_LEN, CustomError::InvalidObservationAccount);
+    Ok(())
+}
+
+// Fields of Raydium PoolState used to verify pool accounts
+pub struct PoolStateInfo {
+    pub amm_config: Pubkey,
+    pub tick_spacing: u16,
+}
+
+// Reads PoolState account owned by `clmm_program_id`
+pub fn read_pool_state(pool: &AccountInfo<'_>, clmm_program_id: &Pubkey) -> Result<PoolStateInfo> {
+    require_keys_eq!(*pool.owner, *clmm_program_id, CustomError::InvalidPool);
+    parse_pool_state(&pool.try_borrow_data()?)
+}
+
+pub fn parse_pool_state(data: &[u8]) -> Result<PoolStateInfo> {
+    require!(data.len() >= POOL_STATE_TICK_SPACING_OFFSET + 2, CustomError::InvalidPool);
+    require!(data[..8] == POOL_STATE_DISCRIMINATOR, CustomError::InvalidPool);
+    Ok(PoolStateInfo {
+        amm_config: read_pubkey(data, POOL_STATE_AMM_CONFIG_OFFSET),
+        tick_spacing: u16::from_le_bytes([data[POOL_STATE_TICK_SPACING_OFFSET], data[POOL_STATE_TICK_SPACING_OFFSET + 1]]),
+    })
+}
+
+// Fields of Raydium PersonalPositionState used to verify position accounts, tick range is in pool orientation
+pub struct PersonalPositionInfo {
+    pub nft_mint: Pubkey,
+    pub pool_id: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+}
+
+// Reads PersonalPositionState account owned by `clmm_program_id`
+pub fn read_personal_position(personal_position: &AccountInfo<'_>, clmm_program_id: &Pubkey) -> Result<PersonalPositionInfo> {
+    require_keys_eq!(*personal_position.owner, *clmm_program_id, CustomError::InvalidPersonalPosition);
+    parse_personal_position(&personal_position.try_borrow_data()?)
+}
+
+pub fn parse_personal_position(data: &[u8]) -> Result<PersonalPositionInfo> {
+    let offset = PERSONAL_POSITION_TICK_LOWER_INDEX_OFFSET;
+    let liquidity_offset = PERSONAL_POSITION_LIQUIDITY_OFFSET;
+    require!(data.len() >= liquidity_offset + 16, CustomError::InvalidPersonalPosition);
+    require!(data[..8] == PERSONAL_POSITION_STATE_DISCRIMINATOR, CustomError::InvalidPersonalPosition);
+    Ok(PersonalPositionInfo {
+        nft_mint: read_pubkey(data, PERSONAL_POSITION_NFT_MINT_OFFSET),
+        pool_id: read_pubkey(data, PERSONAL_POSITION_POOL_ID_OFFSET),
+        tick_lower_index: i32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()),
+        tick_upper_index: i32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()),
+        liquidity: u128::from_le_bytes(data[liquidity_offset..liquidity_offset + 16].try_into().unwrap()),
+    })
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    fn account_discriminator(name: &str) -> [u8; 8] {
+        hash(format!("account:{name}").as_bytes()).to_bytes()[..8].try_into().unwrap()
+    }
+
+    #[test]
+    fn discriminators_match_account_names() {
+        assert_eq!(account_discriminator("AmmConfig"), AMM_CONFIG_DISCRIMINATOR);
+        assert_eq!(account_discriminator("PoolState"), POOL_STATE_DISCRIMINATOR);
+        assert_eq!(account_discriminator("PersonalPositionState"), PERSONAL_POSITION_STATE_DISCRIMINATOR);
+    }
+
+    #[test]
+    fn parses_pool_state_and_personal_position() {
+        let amm_config = Pubkey::new_unique();
+        let mut pool = vec![0u8; 1544];  // PoolState::LEN
+        pool[..8].copy_from_slice(&POOL_STATE_DISCRIMINATOR);
+        pool[9..41].copy_from_slice(amm_config.as_ref());
+        pool[235..237].copy_from_slice(&60u16.to_le_bytes());
+        let pool_state = parse_pool_state(&pool).unwrap();
+        assert_eq!(pool_state.amm_config, amm_config);
+        assert_eq!(pool_state.tick_spacing, 60);
+        assert!(parse_pool_state(&pool[..236]).is_err());
+        pool[0] = 0;
+        assert!(parse_pool_state(&pool).is_err());
+
+        let nft_mint = Pubkey::new_unique();
+        let pool_id = Pubkey::new_unique();
+        let mut position = vec![0u8; 281];  // PersonalPositionState::LEN
+        position[..8].copy_from_slice(&PERSONAL_POSITION_STATE_DISCRIMINATOR);
+        position[9..41].copy_from_slice(nft_mint.as_ref());
+        position[41..73].copy_from_slice(pool_id.as_ref());
+        position[73..77].copy_from_slice(&(-3600i32).to_le_bytes());
+        position[77..81].copy_from_slice(&3540i32.to_le_bytes());
+        position[81..97].copy_from_slice(&(u64::MAX as u128 + 1).to_le_bytes());
+        let personal_position = parse_personal_position(&position).unwrap();
+        assert_eq!(personal_position.nft_mint, nft_mint);
+        assert_eq!(personal_position.pool_id, pool_id);
+        assert_eq!((personal_position.tick_lower_index, personal_position.tick_upper_index), (-3600, 3540));
+        assert_eq!(personal_position.liquidity, u64::MAX as u128 + 1);
+        assert!(parse_personal_position(&position[..96]).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+use crate::metadata;
+use crate::state::{SymbolRegistry, SYMBOL_REGISTRY_SEED};
+use crate::CustomError;
+
+// Accounts used to register symbol of a created token
+pub struct RegisterSymbolAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub symbol_registry: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+// Creates SymbolRegistry of a normalized symbol for the token, failing with a dedicated error
+// if the symbol is reserved or was already registered by another token
+pub fn register_symbol(accounts: RegisterSymbolAccounts<'_>, symbol: &str) -> Result<()> {
+    let (address, bump) = Pubkey::find_program_address(&[SYMBOL_REGISTRY_SEED, symbol.as_bytes()], &crate::ID);
+    require_keys_eq!(address, accounts.symbol_registry.key(), CustomError::InvalidSymbolRegistry);
+    if accounts.symbol_registry.owner == &crate::ID {
+        let symbol_registry = SymbolRegistry::try_deserialize(&mut &accounts.symbol_registry.try_borrow_data()?[..])?;
+        if symbol_registry.reserved {
+            return err!(CustomError::SymbolReserved);
+        }
+        return err!(CustomError::SymbolTaken);
+    }
+
+    let space = 8 + SymbolRegistry::INIT_SPACE;
+    let seeds = &[SYMBOL_REGISTRY_SEED, symbol.as_bytes(), &[bump]];
+    create_pda_account(&accounts, space, &[&seeds[..]])?;
+    SymbolRegistry {
+        symbol: symbol.to_string(),
+        token_mint: accounts.token_mint.key(),
+        creator: accounts.payer.key(),
+        reserved: false,
+        bump,
+    }.try_serialize(&mut &mut accounts.symbol_registry.try_borrow_mut_data()?[..])
+}
+
+// Requires SymbolRegistry of the normalized `symbol` to be registered by the token, so tokens created
+// outside the program cannot be launched with a reserved symbol or one taken by another token
+pub fn check_symbol_registered(symbol_registry: &AccountInfo<'_>, symbol: &str, token_mint: &Pubkey) -> Result<()> {
+    let symbol = metadata::normalize_symbol(symbol)?;
+    let address = Pubkey::find_program_address(&[SYMBOL_REGISTRY_SEED, symbol.as_bytes()], &crate::ID).0;
+    require_keys_eq!(address, symbol_registry.key(), CustomError::InvalidSymbolRegistry);
+    require_keys_eq!(*symbol_registry.owner, crate::ID, CustomError::SymbolNotRegistered);
+    let symbol_registry = SymbolRegistry::try_deserialize(&mut &symbol_registry.try_borrow_data()?[..])?;
+    require!(!symbol_registry.reserved, CustomError::SymbolReserved);
+    require_keys_eq!(symbol_registry.token_mint, *token_mint, CustomError::SymbolTaken);
+    Ok(())
+}
+
+// Creates program-owned PDA account. Unlike system create_account, this also works if someone
+// has already transferred lamports to the address, which would otherwise block the symbol.
+fn create_pda_account(accounts: &RegisterSymbolAccounts<'_>, space: usize, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = accounts.symbol_registry.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                accounts.system_program.clone(),
+                CreateAccount {
+                    from: accounts.payer.clone(),
+                    to: accounts.symbol_registry.clone(),
+                },
+                signer_seeds,
+            ),
+            rent_exempt_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+    let missing_lamports = rent_exempt_lamports.saturating_sub(current_lamports);
+    if missing_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.clone(),
+                Transfer {
+                    from: accounts.payer.clone(),
+                    to: accounts.symbol_registry.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            Allocate {
+                account_to_allocate: accounts.symbol_registry.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            accounts.system_program.clone(),
+            Assign {
+                account_to_assign: accounts.symbol_registry.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
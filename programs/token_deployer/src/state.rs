@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+pub const LAUNCHER_CONFIG_SEED: &[u8] = b"launcher_config";
+
+#[account]
+#[derive(InitSpace)]
+pub struct LauncherConfig {
+    pub admin: Pubkey,
+    pub fee_amount: u64,                   // Lamports charged per launch in add_to_raydium
+    pub fee_receiver: Pubkey,              // Receives launch fees and harvested position fees
+    pub clmm_program_id: Pubkey,           // Raydium CLMM program
+    pub min_liquidity_bps_of_supply: u64,  // Share of total supply, in basis points, that must be provided to liquidity pool
+    pub bump: u8,
+    pub creator_fee_share_bps: u16,        // Share of harvested fees paid to launch creator
+    pub keeper_tip_lamports: u64,          // Paid to harvest_fees caller out of fee receiver's WSOL share
+    pub creator_withheld_fee_share_bps: u16,  // Share of Token-2022 withheld transfer fees paid to launch creator
+    pub min_supply: u64,                   // Minimum total supply of created tokens, in whole tokens
+    pub max_supply: u64,                   // Maximum total supply of created tokens, in whole tokens
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateConfigArgs {
+    pub admin: Option<Pubkey>,
+    pub fee_amount: Option<u64>,
+    pub fee_receiver: Option<Pubkey>,
+    pub clmm_program_id: Option<Pubkey>,
+    pub min_liquidity_bps_of_supply: Option<u64>,
+    pub creator_fee_share_bps: Option<u16>,
+    pub keeper_tip_lamports: Option<u64>,
+    pub creator_withheld_fee_share_bps: Option<u16>,
+    pub min_supply: Option<u64>,
+    pub max_supply: Option<u64>,
+}
+
+pub const LAUNCH_RECORD_SEED: &[u8] = b"launch_record";
+
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchRecord {
+    pub creator: Pubkey,
+    pub token_mint: Pubkey,
+    pub pool_id: Pubkey,
+    pub position_nft_mint: Pubkey,
+    pub token_is_mint_a: bool,         // Pool values below are in pool orientation (price of side A in side B)
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub initial_sqrt_price_x64: u128,
+    pub liquidity: u128,
+    pub launch_slot: u64,
+    pub launch_timestamp: i64,
+    pub token_harvested: u64,          // Total token fees harvested from the position
+    pub wsol_harvested: u64,           // Total WSOL fees harvested from the position
+    pub bump: u8,
+}
+
+// Withdraw-withheld authority of Token-2022 tokens created with transfer fee
+pub const WITHHELD_FEE_AUTHORITY_SEED: &[u8] = b"withheld_fee_authority";
+
+pub const SYMBOL_REGISTRY_SEED: &[u8] = b"symbol_registry";
+
+// Registers a normalized symbol to the token created with it, so each symbol is launched only once.
+// Reserved symbols are registered by admin without a token and can not be used.
+#[account]
+#[derive(InitSpace)]
+pub struct SymbolRegistry {
+    #[max_len(10)]
+    pub symbol: String,
+    pub token_mint: Pubkey,            // Default for reserved symbols
+    pub creator: Pubkey,               // Admin for reserved symbols
+    pub reserved: bool,
+    pub bump: u8,
+}
+
+pub const CREATOR_ESCROW_SEED: &[u8] = b"creator_escrow";
+
+// Holds creator's share of fees (lamports and tokens in its associated token account)
+// when creator's own accounts do not exist at harvest time
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorEscrow {
+    pub creator: Pubkey,
+    pub token_mint: Pubkey,
+    pub bump: u8,
+}
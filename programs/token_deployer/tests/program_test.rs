@@ -0,0 +1,740 @@
+// Integration tests running the program natively in solana-program-test.
+//
+// SPL Token, Token-2022, Associated Token Account and Memo programs are bundled with solana-program-test.
+// Tests marked as ignored also need Raydium CLMM and Metaplex Token Metadata programs, loaded from
+// tests/fixtures (see tests/fixtures/README.md). Run them with `cargo test -p token_deployer --features localnet -- --ignored`.
+
+use anchor_lang::{AccountDeserialize, AccountSerialize, Space};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use anchor_spl::metadata::mpl_token_metadata;
+use anchor_spl::token::spl_token::{self, native_mint};
+use anchor_spl::token_2022::{self, spl_token_2022};
+use anchor_spl::token_interface::spl_token_metadata_interface;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use spl_token_2022::extension::{metadata_pointer, ExtensionType};
+use spl_token_2022::instruction::AuthorityType;
+use solana_program_test::{processor, tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::account_info::AccountInfo;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::entrypoint::ProgramResult;
+use solana_sdk::instruction::{AccountMeta, Instruction, InstructionError};
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::{Transaction, TransactionError};
+use token_deployer::{math, raydium};
+use token_deployer::state::{CreatorEscrow, LaunchRecord, LauncherConfig, SymbolRegistry, CREATOR_ESCROW_SEED, LAUNCH_RECORD_SEED};
+use token_deployer::CustomError;
+use token_deployer_client::pda::{self, RaydiumAccounts};
+use token_deployer_client::{
+    AddToRaydium, ClaimCreatorFees, CreateToken, CreateToken2022, HarvestFees, HarvestWithheldFees, Initialize, LaunchAccounts,
+    MEMO_PROGRAM_ID,
+};
+
+const SUPPLY: u64 = 1_000_000_000_000_000_000;  // 1B tokens with 9 decimals
+const DECIMALS: u8 = 9;
+const TICK_SPACING: u16 = 60;
+const INITIAL_FDV_LAMPORTS: u64 = 100_000_000_000;  // 100 SOL
+const UPPER_FDV_LAMPORTS: u64 = 100_000_000_000_000;  // 100k SOL
+// Raydium AmmConfig discriminator and size (AmmConfig::LEN)
+const AMM_CONFIG_DISCRIMINATOR: [u8; 8] = [218, 244, 33, 104, 203, 203, 43, 111];
+const AMM_CONFIG_LEN: usize = 117;
+
+// Anchor's entrypoint ties lifetime of the accounts slice to lifetime of account infos
+fn entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(accounts.to_vec().into_boxed_slice());
+    token_deployer::entry(program_id, accounts, data)
+}
+
+fn program_test(fee_receiver: Pubkey) -> ProgramTest {
+    let mut program_test = ProgramTest::new("token_deployer", token_deployer::ID, processor!(entry));
+    // Launcher config is injected, as initialize_config requires the program to be deployed as upgradeable
+    let (config, bump) = Pubkey::find_program_address(&[token_deployer::state::LAUNCHER_CONFIG_SEED], &token_deployer::ID);
+    let launcher_config = LauncherConfig {
+        admin: Pubkey::new_unique(),
+        fee_amount: 400_000_000,
+        fee_receiver,
+        clmm_program_id: token_deployer::CLMM_PROGRAM_ID,
+        min_liquidity_bps_of_supply: 9_500,
+        bump,
+        creator_fee_share_bps: 5_000,
+        keeper_tip_lamports: 1_000_000,
+        creator_withheld_fee_share_bps: 5_000,
+        min_supply: 1,
+        max_supply: 1_000_000_000,
+    };
+    let mut data = Vec::with_capacity(8 + LauncherConfig::INIT_SPACE);
+    launcher_config.try_serialize(&mut data).unwrap();
+    program_test.add_account(config, program_account(data, token_deployer::ID));
+    program_test
+}
+
+// Program test with Raydium CLMM and Metaplex fixtures and 1% AMM config
+fn raydium_program_test(fee_receiver: Pubkey) -> (ProgramTest, Pubkey) {
+    let mut program_test = program_test(fee_receiver);
+    program_test.add_program("raydium_clmm", token_deployer::CLMM_PROGRAM_ID, None);
+    program_test.add_program("mpl_token_metadata", mpl_token_metadata::ID, None);
+    let amm_config = add_amm_config(&mut program_test);
+    (program_test, amm_config)
+}
+
+// Injects Raydium AMM config with index 0, 1% trade fee and TICK_SPACING
+fn add_amm_config(program_test: &mut ProgramTest) -> Pubkey {
+    let (amm_config, bump) = Pubkey::find_program_address(&[b"amm_config", &0u16.to_be_bytes()], &token_deployer::CLMM_PROGRAM_ID);
+    let mut data = Vec::with_capacity(AMM_CONFIG_LEN);
+    data.extend_from_slice(&AMM_CONFIG_DISCRIMINATOR);
+    data.push(bump);
+    data.extend_from_slice(&0u16.to_le_bytes());  // index
+    data.extend_from_slice(Pubkey::new_unique().as_ref());  // owner
+    data.extend_from_slice(&120_000u32.to_le_bytes());  // protocol_fee_rate
+    data.extend_from_slice(&10_000u32.to_le_bytes());  // trade_fee_rate (1%)
+    data.extend_from_slice(&TICK_SPACING.to_le_bytes());
+    data.extend_from_slice(&40_000u32.to_le_bytes());  // fund_fee_rate
+    data.resize(AMM_CONFIG_LEN, 0);
+    program_test.add_account(amm_config, program_account(data, token_deployer::CLMM_PROGRAM_ID));
+    amm_config
+}
+
+fn program_account(data: Vec<u8>, owner: Pubkey) -> Account {
+    Account { lamports: 1_000_000_000, data, owner, executable: false, rent_epoch: 0 }
+}
+
+async fn send(context: &mut ProgramTestContext, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let blockhash = context.banks_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(instructions, Some(&context.payer.pubkey()), &all_signers, blockhash);
+    context.banks_client.process_transaction(transaction).await
+}
+
+fn assert_custom_error(result: Result<(), BanksClientError>, error: CustomError) {
+    let code = anchor_lang::error::ERROR_CODE_OFFSET + error as u32;
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(actual)) => assert_eq!(actual, code),
+        err => panic!("unexpected error {err:?}"),
+    }
+}
+
+async fn account<T: AccountDeserialize>(context: &mut ProgramTestContext, address: &Pubkey) -> T {
+    let account = context.banks_client.get_account(*address).await.unwrap().expect("account does not exist");
+    T::try_deserialize(&mut &account.data[..]).unwrap()
+}
+
+fn create_token_2022(context: &ProgramTestContext, token_mint: &Keypair, amount: u64, symbol: &str) -> Instruction {
+    CreateToken2022 {
+        payer: context.payer.pubkey(),
+        token_mint: token_mint.pubkey(),
+        amount,
+        decimals: DECIMALS,
+        name: "Rocket".to_string(),
+        symbol: symbol.to_string(),
+        uri: "https://example.com/rocket.json".to_string(),
+        transfer_fee_basis_points: 100,
+    }.instruction().unwrap()
+}
+
+#[tokio::test]
+async fn initialize_creates_position_nft_owner() {
+    let mut context = program_test(Pubkey::new_unique()).start_with_context().await;
+    let initialize = Initialize { payer: context.payer.pubkey() }.instruction();
+    send(&mut context, std::slice::from_ref(&initialize), &[]).await.unwrap();
+    let position_nft_owner = context.banks_client.get_account(pda::position_nft_owner_pda()).await.unwrap().unwrap();
+    assert_eq!(position_nft_owner.owner, token_deployer::ID);
+    // Initialization is idempotent
+    context.get_new_latest_blockhash().await.unwrap();
+    send(&mut context, &[initialize], &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn create_token_2022_mints_supply_and_registers_symbol() {
+    let mut context = program_test(Pubkey::new_unique()).start_with_context().await;
+    let token_mint = Keypair::new();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY, " rkt ");
+    send(&mut context, &[instruction], &[&token_mint]).await.unwrap();
+
+    let mint: Mint = account(&mut context, &token_mint.pubkey()).await;
+    assert_eq!(mint.supply, SUPPLY);
+    assert_eq!(mint.decimals, DECIMALS);
+    assert!(mint.mint_authority.is_none());
+    let token_account_address = get_associated_token_address_with_program_id(&context.payer.pubkey(), &token_mint.pubkey(), &token_2022::ID);
+    let token_account: TokenAccount = account(&mut context, &token_account_address).await;
+    assert_eq!(token_account.amount, SUPPLY);
+    let symbol_registry: SymbolRegistry = account(&mut context, &pda::symbol_registry("RKT")).await;
+    assert_eq!(symbol_registry.symbol, "RKT");
+    assert_eq!(symbol_registry.token_mint, token_mint.pubkey());
+    assert_eq!(symbol_registry.creator, context.payer.pubkey());
+    assert!(!symbol_registry.reserved);
+
+    // Symbol can only be used once
+    let other_mint = Keypair::new();
+    let instruction = create_token_2022(&context, &other_mint, SUPPLY, "RKT");
+    assert_custom_error(send(&mut context, &[instruction], &[&other_mint]).await, CustomError::SymbolTaken);
+}
+
+#[tokio::test]
+async fn create_token_2022_checks_supply_bounds() {
+    let mut context = program_test(Pubkey::new_unique()).start_with_context().await;
+    let token_mint = Keypair::new();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY + 10u64.pow(DECIMALS as u32), "RKT");
+    assert_custom_error(send(&mut context, &[instruction], &[&token_mint]).await, CustomError::SupplyTooHigh);
+}
+
+// Program test for add_to_raydium of Token-2022 tokens without Raydium CLMM fixture, returning AMM config.
+// Instructions fail at the observation account check once mint invariants are satisfied.
+async fn start_without_raydium(fee_receiver: Pubkey) -> (ProgramTestContext, Pubkey) {
+    let mut program_test = program_test(fee_receiver);
+    let amm_config = add_amm_config(&mut program_test);
+    // Metaplex program is only checked to be executable before the observation account is checked
+    program_test.add_account(mpl_token_metadata::ID, Account { executable: true, ..program_account(vec![], solana_sdk::bpf_loader::ID) });
+    (program_test.start_with_context().await, amm_config)
+}
+
+// Keypair of a mint that is side A of its WSOL pool, so prices and ticks need no inversion
+fn side_a_mint_keypair() -> Keypair {
+    std::iter::repeat_with(Keypair::new)
+        .find(|keypair| pda::token_is_mint_a(&keypair.pubkey(), &native_mint::ID))
+        .unwrap()
+}
+
+async fn add_to_raydium_without_observation(
+    context: &mut ProgramTestContext,
+    token_mint: &Pubkey,
+    amm_config: &Pubkey,
+    fee_receiver: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let (builder, [_, position_nft_mint, wsol_account]) =
+        add_to_raydium_builder(context, token_mint, token_2022::ID, amm_config, fee_receiver, SUPPLY);
+    send(context, &[builder.instruction().unwrap()], &[&position_nft_mint, &wsol_account]).await
+}
+
+// Creates Token-2022 mint outside create_token_2022 and mints SUPPLY to the payer.
+// `metadata` is None for no metadata, or whether metadata stored on the mint is immutable.
+async fn create_mint_2022(context: &mut ProgramTestContext, revoke_mint_authority: bool, freeze_authority: bool, metadata: Option<bool>) -> Keypair {
+    let payer = context.payer.pubkey();
+    let token_mint = side_a_mint_keypair();
+    let mint = token_mint.pubkey();
+    let extensions = if metadata.is_some() { vec![ExtensionType::MetadataPointer] } else { vec![] };
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions).unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    // Extra lamports cover metadata appended to the mint
+    let mut instructions = vec![system_instruction::create_account(&payer, &mint, rent.minimum_balance(space + 512), space as u64, &token_2022::ID)];
+    if metadata.is_some() {
+        instructions.push(metadata_pointer::instruction::initialize(&token_2022::ID, &mint, None, Some(mint)).unwrap());
+    }
+    let freeze_authority = freeze_authority.then_some(payer);
+    instructions.push(spl_token_2022::instruction::initialize_mint2(&token_2022::ID, &mint, &payer, freeze_authority.as_ref(), DECIMALS).unwrap());
+    if let Some(immutable) = metadata {
+        instructions.push(spl_token_metadata_interface::instruction::initialize(
+            &token_2022::ID,
+            &mint,
+            &payer,
+            &mint,
+            &payer,
+            "Rocket".to_string(),
+            "RKT".to_string(),
+            "https://example.com/rocket.json".to_string(),
+        ));
+        if immutable {
+            instructions.push(spl_token_metadata_interface::instruction::update_authority(&token_2022::ID, &mint, &payer, None.try_into().unwrap()));
+        }
+    }
+    let token_account = get_associated_token_address_with_program_id(&payer, &mint, &token_2022::ID);
+    instructions.push(create_associated_token_account(&payer, &payer, &mint, &token_2022::ID));
+    instructions.push(spl_token_2022::instruction::mint_to(&token_2022::ID, &mint, &token_account, &payer, &[], SUPPLY).unwrap());
+    if revoke_mint_authority {
+        instructions.push(spl_token_2022::instruction::set_authority(&token_2022::ID, &mint, None, AuthorityType::MintTokens, &payer, &[]).unwrap());
+    }
+    send(context, &instructions, &[&token_mint]).await.unwrap();
+    token_mint
+}
+
+#[tokio::test]
+async fn add_to_raydium_requires_observation_account() {
+    let fee_receiver = Pubkey::new_unique();
+    let (mut context, amm_config) = start_without_raydium(fee_receiver).await;
+    let token_mint = side_a_mint_keypair();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY, "RKT");
+    send(&mut context, &[instruction], &[&token_mint]).await.unwrap();
+    let result = add_to_raydium_without_observation(&mut context, &token_mint.pubkey(), &amm_config, &fee_receiver).await;
+    assert_custom_error(result, CustomError::InvalidObservationAccount);
+}
+
+#[tokio::test]
+async fn add_to_raydium_checks_mint_invariants() {
+    let fee_receiver = Pubkey::new_unique();
+    let (mut context, amm_config) = start_without_raydium(fee_receiver).await;
+    let cases = [
+        (false, false, Some(true), CustomError::MintAuthorityNotRevoked),
+        (true, true, Some(true), CustomError::FreezeAuthorityNotRevoked),
+        (true, false, None, CustomError::MissingTokenMetadata),
+        (true, false, Some(false), CustomError::MutableTokenMetadata),
+        (true, false, Some(true), CustomError::SymbolNotRegistered),
+    ];
+    for (revoke_mint_authority, freeze_authority, metadata, error) in cases {
+        let token_mint = create_mint_2022(&mut context, revoke_mint_authority, freeze_authority, metadata).await;
+        let result = add_to_raydium_without_observation(&mut context, &token_mint.pubkey(), &amm_config, &fee_receiver).await;
+        assert_custom_error(result, error);
+    }
+
+    // Symbol registered by another token
+    let token_mint = side_a_mint_keypair();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY, "RKT");
+    send(&mut context, &[instruction], &[&token_mint]).await.unwrap();
+    let other_mint = create_mint_2022(&mut context, true, false, Some(true)).await;
+    let result = add_to_raydium_without_observation(&mut context, &other_mint.pubkey(), &amm_config, &fee_receiver).await;
+    assert_custom_error(result, CustomError::SymbolTaken);
+
+    // Supply held outside the payer's account
+    let payer = context.payer.pubkey();
+    let holder = Pubkey::new_unique();
+    let instructions = [
+        create_associated_token_account(&payer, &holder, &token_mint.pubkey(), &token_2022::ID),
+        spl_token_2022::instruction::transfer_checked(
+            &token_2022::ID,
+            &get_associated_token_address_with_program_id(&payer, &token_mint.pubkey(), &token_2022::ID),
+            &token_mint.pubkey(),
+            &get_associated_token_address_with_program_id(&holder, &token_mint.pubkey(), &token_2022::ID),
+            &payer,
+            &[],
+            1,
+            DECIMALS,
+        ).unwrap(),
+    ];
+    send(&mut context, &instructions, &[]).await.unwrap();
+    let result = add_to_raydium_without_observation(&mut context, &token_mint.pubkey(), &amm_config, &fee_receiver).await;
+    assert_custom_error(result, CustomError::SupplyNotHeldByPayer);
+}
+
+#[tokio::test]
+async fn add_to_raydium_checks_raydium_addresses() {
+    let fee_receiver = Pubkey::new_unique();
+    let (mut context, amm_config) = start_without_raydium(fee_receiver).await;
+    let token_mint = side_a_mint_keypair();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY, "RKT");
+    send(&mut context, &[instruction], &[&token_mint]).await.unwrap();
+    let (builder, [observation, position_nft_mint, wsol_account]) =
+        add_to_raydium_builder(&context, &token_mint.pubkey(), token_2022::ID, &amm_config, &fee_receiver, SUPPLY);
+    // Observation account as created by the caller before add_to_raydium
+    let observation_account = program_account(vec![0; raydium::OBSERVATION_STATE_LEN], token_deployer::CLMM_PROGRAM_ID);
+    context.set_account(&observation.pubkey(), &observation_account.into());
+    let raydium = builder.raydium_accounts().unwrap();
+    let cases = [
+        (fee_receiver, CustomError::InvalidFeeReceiver),
+        (token_deployer::CLMM_PROGRAM_ID, CustomError::InvalidClmmProgram),
+        (native_mint::ID, CustomError::InvalidWsolMint),
+        (raydium.pool_id, CustomError::InvalidPool),
+        (raydium.token_vault, CustomError::InvalidPoolVault),
+        (raydium.wsol_vault, CustomError::InvalidPoolVault),
+        (raydium.ex_tick_array_bitmap, CustomError::InvalidTickArrayBitmapExtension),
+        (raydium.protocol_position, CustomError::InvalidProtocolPosition),
+        (raydium.personal_position, CustomError::InvalidPersonalPosition),
+        (raydium.tick_array_lower, CustomError::InvalidTickArray),
+    ];
+    for (address, error) in cases {
+        let mut instruction = builder.instruction().unwrap();
+        let substitute = Pubkey::new_unique();
+        for meta in instruction.accounts.iter_mut().filter(|meta| meta.pubkey == address) {
+            meta.pubkey = substitute;
+        }
+        let result = send(&mut context, &[instruction], &[&position_nft_mint, &wsol_account]).await;
+        assert_custom_error(result, error);
+    }
+}
+
+#[tokio::test]
+async fn add_to_raydium_checks_wsol_amount_overflow() {
+    let fee_receiver = Pubkey::new_unique();
+    let (mut context, amm_config) = start_without_raydium(fee_receiver).await;
+    let token_mint = side_a_mint_keypair();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY, "RKT");
+    send(&mut context, &[instruction], &[&token_mint]).await.unwrap();
+    let (mut builder, [observation, position_nft_mint, wsol_account]) =
+        add_to_raydium_builder(&context, &token_mint.pubkey(), token_2022::ID, &amm_config, &fee_receiver, SUPPLY);
+    let observation_account = program_account(vec![0; raydium::OBSERVATION_STATE_LEN], token_deployer::CLMM_PROGRAM_ID);
+    context.set_account(&observation.pubkey(), &observation_account.into());
+    // Rent of the temporary WSOL account is added to wsol_amount_max
+    builder.wsol_amount_max = u64::MAX;
+    let result = send(&mut context, &[builder.instruction().unwrap()], &[&position_nft_mint, &wsol_account]).await;
+    assert_custom_error(result, CustomError::ArithmeticOverflow);
+}
+
+#[tokio::test]
+async fn harvest_withheld_fees_of_token_not_launched() {
+    let fee_receiver = Pubkey::new_unique();
+    let mut context = program_test(fee_receiver).start_with_context().await;
+    let token_mint = side_a_mint_keypair();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY, "RKT");
+    send(&mut context, &[instruction], &[&token_mint]).await.unwrap();
+
+    // Transfer withholds 1% fee in the holder's account
+    let payer = context.payer.pubkey();
+    let holder = Pubkey::new_unique();
+    let holder_token_account = get_associated_token_address_with_program_id(&holder, &token_mint.pubkey(), &token_2022::ID);
+    let instructions = [
+        create_associated_token_account(&payer, &holder, &token_mint.pubkey(), &token_2022::ID),
+        spl_token_2022::instruction::transfer_checked(
+            &token_2022::ID,
+            &get_associated_token_address_with_program_id(&payer, &token_mint.pubkey(), &token_2022::ID),
+            &token_mint.pubkey(),
+            &holder_token_account,
+            &payer,
+            &[],
+            1_000_000,
+            DECIMALS,
+        ).unwrap(),
+    ];
+    send(&mut context, &instructions, &[]).await.unwrap();
+
+    let harvest_withheld_fees = |symbol: Option<&str>| {
+        HarvestWithheldFees {
+            payer,
+            token_mint: token_mint.pubkey(),
+            creator: payer,
+            symbol: symbol.map(str::to_string),
+            token_accounts: vec![holder_token_account],
+            fee_receiver,
+        }.instruction().unwrap()
+    };
+    // Creator is taken from symbol registry, as there is no launch record
+    let result = send(&mut context, &[harvest_withheld_fees(None)], &[]).await;
+    assert_custom_error(result, CustomError::MissingCreatorAccounts);
+    send(&mut context, &[harvest_withheld_fees(Some("RKT"))], &[]).await.unwrap();
+    // 10_000 withheld, half of it paid to fee receiver with the transfer fee withheld again
+    let fee_receiver_token_account: TokenAccount = account(
+        &mut context,
+        &get_associated_token_address_with_program_id(&fee_receiver, &token_mint.pubkey(), &token_2022::ID),
+    ).await;
+    assert_eq!(fee_receiver_token_account.amount, 4_950);
+}
+
+#[tokio::test]
+async fn claim_creator_fees_without_escrow_token_account() {
+    let fee_receiver = Pubkey::new_unique();
+    let mut context = program_test(fee_receiver).start_with_context().await;
+    let token_mint = side_a_mint_keypair();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY, "RKT");
+    send(&mut context, &[instruction], &[&token_mint]).await.unwrap();
+
+    // Escrow that only received SOL, so it has no associated token account
+    let creator = context.payer.pubkey();
+    let (creator_escrow, bump) =
+        Pubkey::find_program_address(&[CREATOR_ESCROW_SEED, token_mint.pubkey().as_ref()], &token_deployer::ID);
+    let mut data = vec![];
+    CreatorEscrow { creator, token_mint: token_mint.pubkey(), bump }.try_serialize(&mut data).unwrap();
+    context.set_account(&creator_escrow, &program_account(data, token_deployer::ID).into());
+
+    let instruction = ClaimCreatorFees {
+        creator,
+        token_mint: token_mint.pubkey(),
+        token_mint_program: token_2022::ID,
+        escrow_holds_tokens: false,
+    }.instruction();
+    send(&mut context, &[instruction], &[]).await.unwrap();
+    let escrow_account = context.banks_client.get_account(creator_escrow).await.unwrap().unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(escrow_account.lamports, rent.minimum_balance(escrow_account.data.len()));
+}
+
+#[tokio::test]
+async fn harvest_fees_requires_creator_with_launch_record() {
+    let fee_receiver = Pubkey::new_unique();
+    let mut context = program_test(fee_receiver).start_with_context().await;
+    let token_mint = side_a_mint_keypair();
+    let instruction = create_token_2022(&context, &token_mint, SUPPLY, "RKT");
+    send(&mut context, &[instruction], &[&token_mint]).await.unwrap();
+
+    // Position NFT held by position_nft_owner_pda and launch record of the position are injected
+    let position_nft_mint = Pubkey::new_unique();
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    let mint = spl_token::state::Mint { supply: 1, is_initialized: true, ..Default::default() };
+    spl_token::state::Mint::pack(mint, &mut data).unwrap();
+    context.set_account(&position_nft_mint, &program_account(data, spl_token::ID).into());
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    let position_nft_account = spl_token::state::Account {
+        mint: position_nft_mint,
+        owner: pda::position_nft_owner_pda(),
+        amount: 1,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    };
+    spl_token::state::Account::pack(position_nft_account, &mut data).unwrap();
+    let position_nft_account = get_associated_token_address_with_program_id(&pda::position_nft_owner_pda(), &position_nft_mint, &spl_token::ID);
+    context.set_account(&position_nft_account, &program_account(data, spl_token::ID).into());
+    let (launch_record_address, bump) =
+        Pubkey::find_program_address(&[LAUNCH_RECORD_SEED, token_mint.pubkey().as_ref()], &token_deployer::ID);
+    let mut launch_record = LaunchRecord {
+        creator: Pubkey::new_unique(),
+        token_mint: token_mint.pubkey(),
+        pool_id: Pubkey::default(),
+        position_nft_mint,
+        token_is_mint_a: true,
+        tick_lower_index: -3600,
+        tick_upper_index: 3600,
+        initial_sqrt_price_x64: 1 << 64,
+        liquidity: 1,
+        launch_slot: 0,
+        launch_timestamp: 0,
+        token_harvested: 0,
+        wsol_harvested: 0,
+        bump,
+    };
+    let amm_config = Pubkey::new_unique();
+    let wsol_recipient_account = Keypair::new();
+    let mut harvest = HarvestFees::from_launch_record(
+        &launch_record,
+        context.payer.pubkey(),
+        wsol_recipient_account.pubkey(),
+        token_2022::ID,
+        amm_config,
+        TICK_SPACING,
+        fee_receiver,
+        token_deployer::CLMM_PROGRAM_ID,
+    );
+    launch_record.pool_id = harvest.raydium_accounts().pool_id;
+    let mut data = Vec::with_capacity(8 + LaunchRecord::INIT_SPACE);
+    launch_record.try_serialize(&mut data).unwrap();
+    context.set_account(&launch_record_address, &program_account(data, token_deployer::ID).into());
+
+    // Leaving out creator accounts would skip creator's share and harvested totals
+    harvest.creator = None;
+    let result = send(&mut context, &[harvest.instruction()], &[&wsol_recipient_account]).await;
+    assert_custom_error(result, CustomError::MissingCreatorAccounts);
+}
+
+// Creates legacy token that is side A of its WSOL pool, so prices and ticks need no inversion
+async fn create_token(context: &mut ProgramTestContext) -> Keypair {
+    let token_mint = side_a_mint_keypair();
+    let instruction = CreateToken {
+        payer: context.payer.pubkey(),
+        token_mint: token_mint.pubkey(),
+        amount: SUPPLY,
+        decimals: DECIMALS,
+        name: "Rocket".to_string(),
+        symbol: "RKT".to_string(),
+        uri: "https://example.com/rocket.json".to_string(),
+    }.instruction().unwrap();
+    send(context, &[instruction], &[&token_mint]).await.unwrap();
+    token_mint
+}
+
+// Launches `token_amount` tokens in a single-sided position, returning position NFT mint
+async fn add_to_raydium(
+    context: &mut ProgramTestContext,
+    token_mint: &Pubkey,
+    amm_config: &Pubkey,
+    fee_receiver: &Pubkey,
+    token_amount: u64,
+) -> Result<Pubkey, BanksClientError> {
+    let (builder, [observation, position_nft_mint, wsol_account]) =
+        add_to_raydium_builder(context, token_mint, spl_token::ID, amm_config, fee_receiver, token_amount);
+    let rent = context.banks_client.get_rent().await?;
+    let instructions = [
+        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+        builder.accounts.create_observation_account(rent.minimum_balance(token_deployer_client::OBSERVATION_ACCOUNT_SPACE as usize)),
+        builder.instruction().unwrap(),
+    ];
+    send(context, &instructions, &[&observation, &position_nft_mint, &wsol_account]).await?;
+    Ok(position_nft_mint.pubkey())
+}
+
+// Builds add_to_raydium for token that is side A, returning it with observation, position NFT mint and WSOL account keypairs
+fn add_to_raydium_builder(
+    context: &ProgramTestContext,
+    token_mint: &Pubkey,
+    token_mint_program: Pubkey,
+    amm_config: &Pubkey,
+    fee_receiver: &Pubkey,
+    token_amount: u64,
+) -> (AddToRaydium, [Keypair; 3]) {
+    let position = math::position_from_market_cap(true, INITIAL_FDV_LAMPORTS, UPPER_FDV_LAMPORTS, SUPPLY, token_amount, TICK_SPACING).unwrap();
+    let (observation, position_nft_mint, wsol_account) = (Keypair::new(), Keypair::new(), Keypair::new());
+    let builder = AddToRaydium {
+        accounts: LaunchAccounts {
+            payer: context.payer.pubkey(),
+            token_mint: *token_mint,
+            token_mint_program,
+            symbol: "RKT".to_string(),
+            amm_config: *amm_config,
+            tick_spacing: TICK_SPACING,
+            observation: observation.pubkey(),
+            position_nft_mint: position_nft_mint.pubkey(),
+            wsol_account: wsol_account.pubkey(),
+            fee_receiver: *fee_receiver,
+            clmm_program: token_deployer::CLMM_PROGRAM_ID,
+        },
+        sqrt_price_x64: position.sqrt_price_x64,
+        tick_lower_index: position.tick_lower_index,
+        tick_upper_index: position.tick_upper_index,
+        liquidity: position.liquidity,
+        token_amount_max: token_amount,
+        wsol_amount_max: 0,
+    };
+    (builder, [observation, position_nft_mint, wsol_account])
+}
+
+// Swaps through the pool with Raydium swap_v2, buying tokens for WSOL or selling them
+async fn swap(context: &mut ProgramTestContext, amm_config: &Pubkey, token_mint: &Pubkey, raydium: &RaydiumAccounts, buy: bool, amount: u64) {
+    let payer = context.payer.pubkey();
+    let token_account = get_associated_token_address_with_program_id(&payer, token_mint, &spl_token::ID);
+    let wsol_account = get_associated_token_address_with_program_id(&payer, &native_mint::ID, &spl_token::ID);
+    let pool: Account = context.banks_client.get_account(raydium.pool_id).await.unwrap().unwrap();
+    let observation = Pubkey::try_from(&pool.data[8 + 1 + 32 * 6..8 + 1 + 32 * 7]).unwrap();  // PoolState::observation_key
+    let (input_account, output_account, input_vault, output_vault, input_mint, output_mint) = if buy {
+        (wsol_account, token_account, raydium.wsol_vault, raydium.token_vault, native_mint::ID, *token_mint)
+    } else {
+        (token_account, wsol_account, raydium.token_vault, raydium.wsol_vault, *token_mint, native_mint::ID)
+    };
+    let mut accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(*amm_config, false),
+        AccountMeta::new(raydium.pool_id, false),
+        AccountMeta::new(input_account, false),
+        AccountMeta::new(output_account, false),
+        AccountMeta::new(input_vault, false),
+        AccountMeta::new(output_vault, false),
+        AccountMeta::new(observation, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(token_2022::ID, false),
+        AccountMeta::new_readonly(MEMO_PROGRAM_ID, false),
+        AccountMeta::new_readonly(input_mint, false),
+        AccountMeta::new_readonly(output_mint, false),
+        AccountMeta::new(raydium.ex_tick_array_bitmap, false),
+        AccountMeta::new(raydium.tick_array_lower, false),
+    ];
+    if buy {
+        accounts.push(AccountMeta::new(raydium.tick_array_upper, false));
+    }
+    let mut data = raydium::sighash("swap_v2").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());  // other_amount_threshold
+    data.extend_from_slice(&0u128.to_le_bytes());  // no sqrt price limit
+    data.push(1);  // is_base_input
+    let mut instructions = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+        create_associated_token_account(&payer, &payer, &native_mint::ID, &spl_token::ID),
+    ];
+    if buy {
+        instructions.push(system_instruction::transfer(&payer, &wsol_account, amount));
+        instructions.push(spl_token::instruction::sync_native(&spl_token::ID, &wsol_account).unwrap());
+    }
+    instructions.push(Instruction { program_id: token_deployer::CLMM_PROGRAM_ID, accounts, data });
+    send(context, &instructions, &[]).await.unwrap();
+}
+
+// Associated token account program's CreateIdempotent
+fn create_associated_token_account(payer: &Pubkey, owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: associated_token::ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(get_associated_token_address_with_program_id(owner, mint, token_program), false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(solana_sdk::system_program::ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: vec![1],
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires Raydium CLMM and Metaplex fixtures, see tests/fixtures/README.md"]
+async fn create_token_mints_supply_with_metadata() {
+    let (program_test, _) = raydium_program_test(Pubkey::new_unique());
+    let mut context = program_test.start_with_context().await;
+    let token_mint = create_token(&mut context).await;
+    let mint: Mint = account(&mut context, &token_mint.pubkey()).await;
+    assert_eq!(mint.supply, SUPPLY);
+    assert!(mint.mint_authority.is_none());
+    let metadata = context.banks_client.get_account(pda::metadata_account(&token_mint.pubkey())).await.unwrap();
+    assert_eq!(metadata.unwrap().owner, mpl_token_metadata::ID);
+}
+
+#[tokio::test]
+#[ignore = "requires Raydium CLMM and Metaplex fixtures, see tests/fixtures/README.md"]
+async fn add_to_raydium_opens_position_and_records_launch() {
+    let fee_receiver = Pubkey::new_unique();
+    let (program_test, amm_config) = raydium_program_test(fee_receiver);
+    let mut context = program_test.start_with_context().await;
+    let initialize = Initialize { payer: context.payer.pubkey() }.instruction();
+    send(&mut context, &[initialize], &[]).await.unwrap();
+    let token_mint = create_token(&mut context).await;
+    let position_nft_mint = add_to_raydium(&mut context, &token_mint.pubkey(), &amm_config, &fee_receiver, SUPPLY).await.unwrap();
+
+    let launch_record: LaunchRecord = account(&mut context, &pda::launch_record(&token_mint.pubkey())).await;
+    assert_eq!(launch_record.creator, context.payer.pubkey());
+    assert_eq!(launch_record.position_nft_mint, position_nft_mint);
+    assert!(launch_record.token_is_mint_a);
+    let raydium = HarvestFees::from_launch_record(
+        &launch_record,
+        context.payer.pubkey(),
+        Pubkey::new_unique(),
+        spl_token::ID,
+        amm_config,
+        TICK_SPACING,
+        fee_receiver,
+        token_deployer::CLMM_PROGRAM_ID,
+    ).raydium_accounts();
+    assert_eq!(launch_record.pool_id, raydium.pool_id);
+    let position_nft_account: TokenAccount = account(&mut context, &raydium.position_nft_account).await;
+    assert_eq!(position_nft_account.owner, pda::position_nft_owner_pda());
+    assert_eq!(position_nft_account.amount, 1);
+    let personal_position = context.banks_client.get_account(raydium.personal_position).await.unwrap().unwrap();
+    assert_eq!(raydium::parse_personal_position(&personal_position.data).unwrap().liquidity, launch_record.liquidity);
+    let fee_receiver_account = context.banks_client.get_account(fee_receiver).await.unwrap().unwrap();
+    assert_eq!(fee_receiver_account.lamports, 400_000_000);
+}
+
+#[tokio::test]
+#[ignore = "requires Raydium CLMM and Metaplex fixtures, see tests/fixtures/README.md"]
+async fn add_to_raydium_rejects_position_under_liquidity_floor() {
+    let fee_receiver = Pubkey::new_unique();
+    let (program_test, amm_config) = raydium_program_test(fee_receiver);
+    let mut context = program_test.start_with_context().await;
+    let initialize = Initialize { payer: context.payer.pubkey() }.instruction();
+    send(&mut context, &[initialize], &[]).await.unwrap();
+    let token_mint = create_token(&mut context).await;
+    // 94% of supply is below the 95% floor
+    let result = add_to_raydium(&mut context, &token_mint.pubkey(), &amm_config, &fee_receiver, SUPPLY / 100 * 94).await;
+    assert_custom_error(result.map(|_| ()), CustomError::InsufficientLiquidityProvided);
+}
+
+#[tokio::test]
+#[ignore = "requires Raydium CLMM and Metaplex fixtures, see tests/fixtures/README.md"]
+async fn harvest_fees_collects_swap_fees() {
+    let fee_receiver = Pubkey::new_unique();
+    let (program_test, amm_config) = raydium_program_test(fee_receiver);
+    let mut context = program_test.start_with_context().await;
+    let initialize = Initialize { payer: context.payer.pubkey() }.instruction();
+    send(&mut context, &[initialize], &[]).await.unwrap();
+    let token_mint = create_token(&mut context).await;
+    // Keep 1% of supply to sell back into the pool
+    add_to_raydium(&mut context, &token_mint.pubkey(), &amm_config, &fee_receiver, SUPPLY / 100 * 99).await.unwrap();
+    let launch_record: LaunchRecord = account(&mut context, &pda::launch_record(&token_mint.pubkey())).await;
+
+    let wsol_recipient_account = Keypair::new();
+    let harvest = HarvestFees::from_launch_record(
+        &launch_record,
+        context.payer.pubkey(),
+        wsol_recipient_account.pubkey(),
+        spl_token::ID,
+        amm_config,
+        TICK_SPACING,
+        fee_receiver,
+        token_deployer::CLMM_PROGRAM_ID,
+    );
+    let raydium = harvest.raydium_accounts();
+    swap(&mut context, &amm_config, &token_mint.pubkey(), &raydium, true, 10_000_000_000).await;
+    swap(&mut context, &amm_config, &token_mint.pubkey(), &raydium, false, SUPPLY / 1000).await;
+
+    let instructions = [ComputeBudgetInstruction::set_compute_unit_limit(1_400_000), harvest.instruction()];
+    send(&mut context, &instructions, &[&wsol_recipient_account]).await.unwrap();
+    let launch_record: LaunchRecord = account(&mut context, &pda::launch_record(&token_mint.pubkey())).await;
+    assert!(launch_record.token_harvested > 0);
+    assert!(launch_record.wsol_harvested > 0);
+    let fee_receiver_token_account = get_associated_token_address_with_program_id(&fee_receiver, &token_mint.pubkey(), &spl_token::ID);
+    let fee_receiver_token_account: TokenAccount = account(&mut context, &fee_receiver_token_account).await;
+    assert!(fee_receiver_token_account.amount > 0);
+}